@@ -0,0 +1,57 @@
+#![allow(non_camel_case_types)]
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use crypto::{
+    algebra::{
+        field::fp_impls::fp_secp256k1::{FP_SECP265K1, P},
+        operations::{BinaryOperation, Multiplicative},
+    },
+    helpers::mul_mod,
+};
+use primitive_types::U256;
+
+// The same prime declared through the generic path, for comparison.
+crypto::prime_field!(FP_SECP265K1_GENERIC, GenericSecp256k1Modulus, P);
+
+fn operands() -> (U256, U256) {
+    let a = U256::from_str_radix(
+        "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+        16,
+    )
+    .unwrap();
+    let b = U256::from_str_radix(
+        "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+        16,
+    )
+    .unwrap();
+    (a, b)
+}
+
+fn bench_mul(c: &mut Criterion) {
+    let (a, b) = operands();
+    let mut group = c.benchmark_group("secp256k1_field_mul");
+
+    group.bench_function("pseudo_mersenne", |bench| {
+        bench.iter(|| {
+            <U256 as BinaryOperation<Multiplicative, FP_SECP265K1>>::op(black_box(&a), black_box(&b))
+        })
+    });
+    group.bench_function("montgomery", |bench| {
+        bench.iter(|| {
+            <U256 as BinaryOperation<Multiplicative, FP_SECP265K1_GENERIC>>::op(
+                black_box(&a),
+                black_box(&b),
+            )
+        })
+    });
+    group.bench_function("u512_division", |bench| {
+        bench.iter(|| mul_mod(black_box(a), black_box(b), P))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_mul);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use crypto::algebra::{
+    elliptic_curve::{element::EllipticCurvePoint, impls::Secp256k1},
+    group::CyclingGroup,
+};
+use primitive_types::U256;
+
+fn scalar() -> U256 {
+    U256::from_str_radix(
+        "AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522",
+        16,
+    )
+    .unwrap()
+}
+
+fn bench_generator_mul(c: &mut Criterion) {
+    let k = scalar();
+    let g = Secp256k1.generator();
+    // Build the generator table outside the measurement.
+    Secp256k1.mul_generator(&k);
+
+    let mut group = c.benchmark_group("secp256k1_generator_mul");
+
+    group.bench_function("fixed_base_table", |bench| {
+        bench.iter(|| Secp256k1.mul_generator(black_box(&k)))
+    });
+    group.bench_function("montgomery_ladder", |bench| {
+        bench.iter(|| black_box(&g).mul_scalar_ct(black_box(&k)))
+    });
+    group.bench_function("wnaf_variable_time", |bench| {
+        bench.iter(|| black_box(&g).mul_scalar(black_box(&k)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_generator_mul);
+criterion_main!(benches);
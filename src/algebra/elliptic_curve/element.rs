@@ -1,4 +1,6 @@
-use crate::algebra::{field::FiniteField, group::{element::{ElementFinite, GroupOps}, Group}, operations::{Additive, Multiplicative}, properties::{Commutative, Distributive, Finite}, AlgebraicStructure};
+use crate::algebra::{field::FiniteField, group::element::{ElementFinite, GroupOps}, operations::{Additive, Multiplicative}, properties::{Commutative, Distributive, Finite}, AlgebraicStructure};
+
+use primitive_types::U256;
 
 use super::EllipticCurve;
 
@@ -25,7 +27,8 @@ where
 {
     fn add(&self, rhs: &Self) -> Self;
     fn double(&self) -> Self;
-    fn mul_scalar(&self) -> Self;
+    /// Returns `scalar · self`, the point added to itself `scalar` times.
+    fn mul_scalar(&self, scalar: &U256) -> Self;
     fn is_on_curve(&self, curve: &E) -> bool;
     fn is_identity(&self) -> bool;
     fn neg(&self) -> Self;
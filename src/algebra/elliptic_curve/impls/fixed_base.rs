@@ -0,0 +1,70 @@
+use std::sync::OnceLock;
+
+use primitive_types::U256;
+
+use crate::{
+    algebra::{elliptic_curve::element::EllipticCurvePoint, group::CyclingGroup},
+    helpers::constant_time::{ct_eq, ConditionallySelectable},
+};
+
+use super::{point::JacobianPoint, Secp256k1};
+
+/// Number of scalar bits handled by each table row.
+const WINDOW: usize = 4;
+
+/// Number of table rows, one per `WINDOW`-bit digit of a 256-bit scalar.
+const ROWS: usize = 256 / WINDOW;
+
+/// One row of multiples `j·16ⁱ·G` for `j = 0, …, 15`.
+type Row = [JacobianPoint; 1 << WINDOW];
+
+/// Precomputed multiples of the generator, built on first use
+/// (64 rows of 16 points, about 100 KiB).
+static GENERATOR_TABLE: OnceLock<Vec<Row>> = OnceLock::new();
+
+fn generator_table() -> &'static [Row] {
+    GENERATOR_TABLE.get_or_init(|| {
+        let mut base = Secp256k1.generator();
+        let mut rows = Vec::with_capacity(ROWS);
+        for _ in 0..ROWS {
+            let mut row = [JacobianPoint::infinity(); 1 << WINDOW];
+            for j in 1..row.len() {
+                row[j] = EllipticCurvePoint::add(&row[j - 1], &base);
+            }
+            rows.push(row);
+            // base = 16ⁱ⁺¹·G
+            for _ in 0..WINDOW {
+                base = base.double();
+            }
+        }
+        rows
+    })
+}
+
+/// Reads `row[digit]` by scanning the whole row with conditional selects,
+/// so the memory access pattern does not reveal the digit.
+fn lookup(row: &Row, digit: u64) -> JacobianPoint {
+    let digit = U256::from(digit);
+    let mut result = JacobianPoint::infinity();
+    for (j, entry) in row.iter().enumerate() {
+        result = JacobianPoint::conditional_select(&result, entry, ct_eq(&U256::from(j), &digit));
+    }
+    result
+}
+
+impl Secp256k1 {
+    /// Computes `scalar · G` for the standard generator `G`.
+    ///
+    /// Splits the scalar into 4-bit digits `dᵢ` and sums the precomputed
+    /// points `dᵢ·16ⁱ·G`: 64 additions and no doublings, several times
+    /// faster than [`JacobianPoint::mul_scalar_ct`] on `G`. The table lookups
+    /// and additions are constant time, so `scalar` may be a secret key.
+    pub fn mul_generator(&self, scalar: &U256) -> JacobianPoint {
+        let mut result = JacobianPoint::infinity();
+        for (i, row) in generator_table().iter().enumerate() {
+            let digit = (scalar.0[i * WINDOW / 64] >> (i * WINDOW % 64)) & 0xF;
+            result = result.add_ct(&lookup(row, digit));
+        }
+        result
+    }
+}
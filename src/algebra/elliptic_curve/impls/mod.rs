@@ -1,10 +1,38 @@
-use point::JacobianPoint;
+use point::{AffinePoint, JacobianPoint};
 use primitive_types::U256;
-use crate::algebra::{field::fp_impls::fp_secp256k1::FP_SECP265K1, group::{CyclingGroup, FiniteGroup, Group}, operations::Additive, properties::Finite, AlgebraicStructure};
+use crate::algebra::{field::fp_impls::fp_secp256k1::FP_SECP265K1, group::{CyclingGroup, FiniteGroup, Group}, operations::Additive, properties::{Finite, Identity}, AlgebraicStructure};
 use super::EllipticCurve;
 
+pub mod fixed_base;
 pub mod point;
+pub mod wnaf;
 
+/// The order `n` of the secp256k1 group, a 256-bit prime.
+pub const N: U256 = U256([
+    0xBFD25E8CD0364141,
+    0xBAAEDCE6AF48A03B,
+    0xFFFFFFFFFFFFFFFE,
+    0xFFFFFFFFFFFFFFFF,
+]);
+
+/// The x-coordinate of the standard generator G.
+pub const GX: U256 = U256([
+    0x59F2815B16F81798,
+    0x029BFCDB2DCE28D9,
+    0x55A06295CE870B07,
+    0x79BE667EF9DCBBAC,
+]);
+
+/// The y-coordinate of the standard generator G.
+pub const GY: U256 = U256([
+    0x9C47D08FFB10D4B8,
+    0xFD17B448A6855419,
+    0x5DA4FBFC0E1108A8,
+    0x483ADA7726A3C465,
+]);
+
+/// The curve `y² = x³ + 7` over the secp256k1 base field.
+#[derive(Debug)]
 pub struct Secp256k1;
 
 
@@ -16,12 +44,16 @@ impl Group<Additive> for Secp256k1 {
 
 }
 
+impl Identity<Additive, Secp256k1> for Secp256k1 {
+    /// Returns the point at infinity, the neutral element of the group.
+    fn identity() -> JacobianPoint {
+        JacobianPoint::infinity()
+    }
+}
+
 impl Finite for Secp256k1 {
     fn order(&self) -> U256 {
-        U256::from_str_radix(
-            "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141", 
-            16
-        ).unwrap()
+        N
     }
 }
 
@@ -29,7 +61,7 @@ impl FiniteGroup<Additive> for Secp256k1 {}
 
 impl CyclingGroup<Additive> for Secp256k1 {
     fn generator(&self) -> Self::Element {
-        
+        JacobianPoint::from(AffinePoint { x: GX, y: GY })
     }
 }
 
@@ -41,4 +73,4 @@ impl EllipticCurve<U256, FP_SECP265K1> for Secp256k1 {
     fn b(&self) -> U256 {
         U256::from(7)
     }
-}
\ No newline at end of file
+}
@@ -1,14 +1,42 @@
 use primitive_types::U256;
 
-use crate::algebra::{elliptic_curve::EllipticCurve, field::FiniteField, group::element::GroupOps, operations::{Additive, Multiplicative}, properties::{Commutative, Distributive, Finite}};
+use crate::{
+    algebra::{
+        elliptic_curve::{element::EllipticCurvePoint, EllipticCurve},
+        field::{
+            fp::FieldModulus,
+            fp_impls::fp_secp256k1::{Secp256k1Modulus, FP_SECP265K1, P},
+        },
+        group::{
+            element::{ElementFinite, GroupOps},
+            extension::ScalarMultiplication,
+        },
+        operations::{Additive, BinaryOperation, Multiplicative},
+        properties::{Associative, Commutative, Invertible},
+        AlgebraicStructure,
+    },
+    helpers::constant_time::{ct_add_mod, ct_is_zero, ct_sub_mod, Choice, ConditionallySelectable},
+};
 
-#[derive(PartialEq, Clone, Copy)]
+use super::{wnaf::wnaf, Secp256k1, N};
+
+/// Window width used by the variable-time [`EllipticCurvePoint::mul_scalar`].
+const WNAF_WIDTH: usize = 5;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct AffinePoint {
     pub x: U256,
     pub y: U256,
 }
 
-#[derive(PartialEq, Clone, Copy)]
+/// A point in Jacobian coordinates `(X, Y, Z)`, representing the
+/// affine point `(X / Z², Y / Z³)`.
+///
+/// Jacobian coordinates let points be added and doubled without
+/// a field inversion; one inversion is needed only to go back to affine form.
+/// A point is only defined up to the scaling `(λ²X, λ³Y, λZ)`, so equality
+/// is projective, and any point with `Z = 0` is the point at infinity.
+#[derive(Debug, Clone, Copy)]
 pub struct JacobianPoint {
     pub x: U256,
     pub y: U256,
@@ -16,30 +44,336 @@ pub struct JacobianPoint {
 }
 
 impl JacobianPoint {
+    /// Returns the point at infinity in its canonical form `(1, 1, 0)`.
     pub fn infinity() -> Self {
-        Self { x: U256::zero(), y: U256::zero(), z: U256::one() }
+        Self { x: U256::one(), y: U256::one(), z: U256::zero() }
     }
 
     pub fn is_infinity(&self) -> bool {
-        self.eq(&Self::infinity())
+        self.z.is_zero()
+    }
+}
+
+impl PartialEq for JacobianPoint {
+    /// Compares projectively: `X₁Z₂² = X₂Z₁²` and `Y₁Z₂³ = Y₂Z₁³`.
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_infinity() || other.is_infinity() {
+            return self.is_infinity() && other.is_infinity();
+        }
+        let z1z1 = mul(&self.z, &self.z);
+        let z2z2 = mul(&other.z, &other.z);
+        mul(&self.x, &z2z2) == mul(&other.x, &z1z1)
+            && mul(&self.y, &mul(&z2z2, &other.z)) == mul(&other.y, &mul(&z1z1, &self.z))
+    }
+}
+
+impl Eq for JacobianPoint {}
+
+impl ConditionallySelectable for JacobianPoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: U256::conditional_select(&a.x, &b.x, choice),
+            y: U256::conditional_select(&a.y, &b.y, choice),
+            z: U256::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+impl JacobianPoint {
+    /// The addition formulas (add-1998-cmo-2) without special cases.
+    ///
+    /// Returns the sum together with `H = U2 − U1` and `r = S2 − S1`.
+    /// The sum is only correct when neither operand is the point at infinity
+    /// and the operands are not the same point (`H = r = 0`).
+    fn add_unchecked(&self, rhs: &Self) -> (Self, U256, U256) {
+        let z1z1 = mul(&self.z, &self.z);
+        let z2z2 = mul(&rhs.z, &rhs.z);
+        let u1 = mul(&self.x, &z2z2);
+        let u2 = mul(&rhs.x, &z1z1);
+        let s1 = mul(&self.y, &mul(&rhs.z, &z2z2));
+        let s2 = mul(&rhs.y, &mul(&self.z, &z1z1));
+
+        let h = sub(&u2, &u1);
+        let r = sub(&s2, &s1);
+
+        let h2 = mul(&h, &h);
+        let h3 = mul(&h2, &h);
+        let u1h2 = mul(&u1, &h2);
+
+        // X3 = r² − H³ − 2·U1·H²
+        let x3 = sub(&sub(&mul(&r, &r), &h3), &add(&u1h2, &u1h2));
+        // Y3 = r·(U1·H² − X3) − S1·H³
+        let y3 = sub(&mul(&r, &sub(&u1h2, &x3)), &mul(&s1, &h3));
+        // Z3 = Z1·Z2·H
+        let z3 = mul(&mul(&self.z, &rhs.z), &h);
+
+        (Self { x: x3, y: y3, z: z3 }, h, r)
+    }
+
+    /// Adds two points without branching on their coordinates.
+    ///
+    /// Both the general sum and the doubling are always computed,
+    /// and the correct result is picked with conditional selects.
+    pub fn add_ct(&self, rhs: &Self) -> Self {
+        let (sum, h, r) = self.add_unchecked(rhs);
+        let doubled = self.double();
+
+        let same_point = ct_is_zero(&h) & ct_is_zero(&r);
+        let result = Self::conditional_select(&sum, &doubled, same_point);
+        let result = Self::conditional_select(&result, rhs, ct_is_zero(&self.z));
+        Self::conditional_select(&result, self, ct_is_zero(&rhs.z))
+    }
+
+    /// Computes `scalar · self` with a Montgomery ladder.
+    ///
+    /// Every one of the 256 scalar bits costs one [`add_ct`](Self::add_ct)
+    /// and one doubling, and the ladder registers are exchanged with
+    /// conditional swaps, so neither the sequence of operations nor the
+    /// memory access pattern depends on `scalar`. This is the path for
+    /// secret scalars such as private keys.
+    pub fn mul_scalar_ct(&self, scalar: &U256) -> Self {
+        // Invariant: r1 = r0 + self
+        let mut r0 = Self::infinity();
+        let mut r1 = *self;
+        for i in (0..256).rev() {
+            let bit = Choice::from_bit(scalar.bit(i) as u64);
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+            r1 = r0.add_ct(&r1);
+            r0 = r0.double();
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+        }
+        r0
     }
 }
 
 impl From<AffinePoint> for JacobianPoint {
+    /// Lifts `(x, y)` to `(x, y, 1)`, reducing both coordinates modulo p
+    /// so that non-canonical representatives still add and compare correctly.
     fn from(value: AffinePoint) -> Self {
-        Self { x: value.x, y: value.y, z: U256::one() }
+        Self { x: value.x % P, y: value.y % P, z: U256::one() }
     }
 }
 
-impl Into<Option<AffinePoint>> for JacobianPoint {
-    fn into(self) -> Option<AffinePoint> {
-        if self.is_infinity() {
+impl From<JacobianPoint> for Option<AffinePoint> {
+    /// Converts to affine coordinates `(X / Z², Y / Z³)`,
+    /// or None for the point at infinity.
+    fn from(value: JacobianPoint) -> Self {
+        if value.is_infinity() {
             None
         } else {
-            Some()
+            let z_inv = <U256 as Invertible<Multiplicative, FP_SECP265K1>>::inverse(&value.z)
+                .expect("Z is nonzero for finite points");
+            let z_inv2 = mul(&z_inv, &z_inv);
+            Some(AffinePoint {
+                x: mul(&value.x, &z_inv2),
+                y: mul(&value.y, &mul(&z_inv2, &z_inv)),
+            })
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Base field shorthands (coordinates are kept in the canonical range [0, p),
+// and none of these branch on the values, so secret points can go through them)
+// -----------------------------------------------------------------------------
+#[inline]
+fn add(a: &U256, b: &U256) -> U256 {
+    ct_add_mod(a, b, &P)
+}
+
+#[inline]
+fn sub(a: &U256, b: &U256) -> U256 {
+    ct_sub_mod(a, b, &P)
+}
+
+#[inline]
+fn mul(a: &U256, b: &U256) -> U256 {
+    Secp256k1Modulus::mul(a, b)
+}
+
+// -----------------------------------------------------------------------------
+// Point arithmetic on y² = x³ + 7 in Jacobian coordinates
+// -----------------------------------------------------------------------------
+impl EllipticCurvePoint<U256, FP_SECP265K1, Secp256k1> for JacobianPoint {
+    /// Adds two points (add-1998-cmo-2), falling back to doubling
+    /// when both operands represent the same point.
+    ///
+    /// Branches on the operands; use [`JacobianPoint::add_ct`] for secret points.
+    fn add(&self, rhs: &Self) -> Self {
+        if self.is_infinity() {
+            return *rhs;
+        }
+        if rhs.is_infinity() {
+            return *self;
+        }
+
+        let (sum, h, r) = self.add_unchecked(rhs);
+        if h.is_zero() && r.is_zero() {
+            self.double()
+        } else {
+            // Same x-coordinate but r ≠ 0 means rhs = −self, and H = 0
+            // already makes the sum the point at infinity
+            sum
+        }
+    }
+
+    /// Doubles a point (dbl-2009-l, for curves with a = 0).
+    ///
+    /// No special cases are needed: for the point at infinity or a point
+    /// with Y = 0, the result has Z3 = 2·Y·Z = 0, the point at infinity.
+    fn double(&self) -> Self {
+        let a = mul(&self.x, &self.x);
+        let b = mul(&self.y, &self.y);
+        let c = mul(&b, &b);
+
+        // D = 2·((X + B)² − A − C)
+        let x_plus_b = add(&self.x, &b);
+        let d = sub(&sub(&mul(&x_plus_b, &x_plus_b), &a), &c);
+        let d = add(&d, &d);
+        // E = 3·A, F = E²
+        let e = add(&add(&a, &a), &a);
+        let f = mul(&e, &e);
+
+        // X3 = F − 2·D
+        let x3 = sub(&f, &add(&d, &d));
+        // Y3 = E·(D − X3) − 8·C
+        let c2 = add(&c, &c);
+        let c4 = add(&c2, &c2);
+        let y3 = sub(&mul(&e, &sub(&d, &x3)), &add(&c4, &c4));
+        // Z3 = 2·Y·Z
+        let yz = mul(&self.y, &self.z);
+        let z3 = add(&yz, &yz);
+
+        Self { x: x3, y: y3, z: z3 }
+    }
+
+    /// Computes `scalar · self` with width-5 wNAF double-and-add,
+    /// using the precomputed odd multiples `self, 3·self, …, 15·self`.
+    ///
+    /// The sequence of operations depends on the bits of `scalar`,
+    /// so this must only be used with public scalars; secret scalars go
+    /// through [`JacobianPoint::mul_scalar_ct`].
+    fn mul_scalar(&self, scalar: &U256) -> Self {
+        let digits = wnaf(scalar, WNAF_WIDTH);
+
+        let twice = self.double();
+        let mut odd_multiples = [*self; 1 << (WNAF_WIDTH - 2)];
+        for i in 1..odd_multiples.len() {
+            odd_multiples[i] = EllipticCurvePoint::add(&odd_multiples[i - 1], &twice);
         }
+
+        let mut result = Self::infinity();
+        for &digit in digits.iter().rev() {
+            result = result.double();
+            if digit > 0 {
+                result = EllipticCurvePoint::add(&result, &odd_multiples[digit as usize / 2]);
+            } else if digit < 0 {
+                result = EllipticCurvePoint::add(&result, &odd_multiples[digit.unsigned_abs() as usize / 2].neg());
+            }
+        }
+        result
+    }
+
+    /// Checks the Jacobian curve equation `Y² = X³ + a·X·Z⁴ + b·Z⁶`.
+    /// The point at infinity is considered to lie on the curve.
+    fn is_on_curve(&self, curve: &Secp256k1) -> bool {
+        if self.is_infinity() {
+            return true;
+        }
+        let z2 = mul(&self.z, &self.z);
+        let z4 = mul(&z2, &z2);
+        let z6 = mul(&z4, &z2);
+
+        let lhs = mul(&self.y, &self.y);
+        let rhs = add(
+            &add(&mul(&mul(&self.x, &self.x), &self.x), &mul(&curve.a(), &mul(&self.x, &z4))),
+            &mul(&curve.b(), &z6),
+        );
+        lhs == rhs
+    }
+
+    fn is_identity(&self) -> bool {
+        self.is_infinity()
+    }
+
+    /// Negates a point: −(X, Y, Z) = (X, −Y, Z).
+    fn neg(&self) -> Self {
+        Self { x: self.x, y: sub(&U256::zero(), &self.y), z: self.z }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for point addition, both in the curve group and
+// on the point type itself (as required by `EllipticCurvePoint`)
+//
+// `op` is the fast variable-time addition; `op_ct` is the branch-free
+// `add_ct`, so that the ladder in `Power::pow_ct` stays constant time.
+// -----------------------------------------------------------------------------
+impl BinaryOperation<Additive, Secp256k1> for JacobianPoint {
+    fn op(a: &JacobianPoint, b: &JacobianPoint) -> JacobianPoint {
+        EllipticCurvePoint::add(a, b)
+    }
+
+    fn op_ct(a: &JacobianPoint, b: &JacobianPoint) -> JacobianPoint {
+        a.add_ct(b)
     }
 }
 
+impl AlgebraicStructure<Additive> for JacobianPoint {
+    type Element = JacobianPoint;
+}
+
+impl BinaryOperation<Additive, JacobianPoint> for JacobianPoint {
+    fn op(a: &JacobianPoint, b: &JacobianPoint) -> JacobianPoint {
+        EllipticCurvePoint::add(a, b)
+    }
+
+    fn op_ct(a: &JacobianPoint, b: &JacobianPoint) -> JacobianPoint {
+        a.add_ct(b)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Group, Commutative, and Associative trait implementations
+// -----------------------------------------------------------------------------
+impl GroupOps<Additive, Secp256k1> for JacobianPoint {}
+
+impl Associative<Additive, Secp256k1> for JacobianPoint {}
 
+impl Commutative<Additive, Secp256k1> for JacobianPoint {}
+impl Commutative<Additive, JacobianPoint> for JacobianPoint {}
 
+// -----------------------------------------------------------------------------
+// Implement Invertible trait for point negation.
+// -----------------------------------------------------------------------------
+impl Invertible<Additive, Secp256k1> for JacobianPoint {
+    fn inverse(a: &JacobianPoint) -> Option<JacobianPoint> {
+        Some(EllipticCurvePoint::neg(a))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement ElementFinite: the group order n is prime, so every point
+// other than infinity has order n.
+// -----------------------------------------------------------------------------
+impl ElementFinite<Additive, Secp256k1> for JacobianPoint {
+    fn element_order(_g: &Secp256k1, element: &JacobianPoint) -> U256 {
+        if element.is_infinity() {
+            U256::one()
+        } else {
+            N
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement ScalarMultiplication by integer scalars
+// -----------------------------------------------------------------------------
+impl ScalarMultiplication<U256> for JacobianPoint {
+    /// Returns `scalar · self` in constant time, since generic callers
+    /// cannot tell whether the scalar is secret
+    /// (see [`JacobianPoint::mul_scalar_ct`]).
+    fn scale(&self, scalar: &U256) -> Self {
+        self.mul_scalar_ct(scalar)
+    }
+}
@@ -0,0 +1,39 @@
+use primitive_types::{U256, U512};
+
+/// Recodes `scalar` into its width-`width` non-adjacent form (wNAF).
+///
+/// Returns the digits least significant first, with
+/// `scalar = Σ digits[i]·2ⁱ`. Every nonzero digit is odd and lies in
+/// `(−2^(width−1), 2^(width−1))`, and any `width` consecutive digits contain
+/// at most one nonzero digit, so a multiplication needs only about
+/// `256 / (width + 1)` additions, all with precomputed odd multiples.
+///
+/// # Panics
+/// If `width` is not in `2..=8`.
+pub fn wnaf(scalar: &U256, width: usize) -> Vec<i8> {
+    assert!((2..=8).contains(&width), "wNAF width must be between 2 and 8");
+
+    let window = 1u64 << width;
+    let half = window >> 1;
+    // Subtracting a negative digit adds to k, which can carry past 256 bits
+    let mut k = U512::from(*scalar);
+    let mut digits = Vec::with_capacity(257);
+
+    while !k.is_zero() {
+        let digit = if k.bit(0) {
+            let m = k.low_u64() & (window - 1);
+            if m >= half {
+                k += U512::from(window - m);
+                m as i64 - window as i64
+            } else {
+                k -= U512::from(m);
+                m as i64
+            }
+        } else {
+            0
+        };
+        digits.push(digit as i8);
+        k >>= 1;
+    }
+    digits
+}
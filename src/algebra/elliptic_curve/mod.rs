@@ -1,6 +1,6 @@
 use element::EllipticCurvePoint;
 
-use super::{field::FiniteField, group::{element::{ElementFinite, GroupOps}, CyclingGroup}, operations::{Additive, Multiplicative}, properties::{Commutative, Distributive, Finite}, AlgebraicStructure};
+use super::{field::FiniteField, group::{element::GroupOps, CyclingGroup}, operations::{Additive, Multiplicative}, properties::{Commutative, Distributive, Finite}, AlgebraicStructure};
 
 pub mod impls;
 pub mod element;
@@ -0,0 +1,171 @@
+// -----------------------------------------------------------------------------
+// External and internal module imports
+// -----------------------------------------------------------------------------
+use std::marker::PhantomData;
+
+use primitive_types::U256;
+
+use crate::algebra::{
+    field::{Field, FiniteField},
+    group::{element::GroupOps, extension::Power, Group},
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{
+        Associative, Commutative, Distributive, Finite, Identity, Invertible,
+    },
+    AlgebraicStructure,
+};
+
+/// Parameters of a **binary field** 𝔽₂ⁿ = 𝔽₂[x] / (f(x)) with `n <= 128`.
+///
+/// Elements are polynomials of degree below `n` over 𝔽₂, stored as `u128`
+/// bit vectors in polynomial basis: bit `i` is the coefficient of `xⁱ`.
+pub trait BinaryFieldConfig: std::fmt::Debug {
+    /// The extension degree `n`.
+    const DEGREE: u32;
+
+    /// The irreducible polynomial `f(x)` without its leading term, i.e. `f(x) − xⁿ`.
+    /// Since `xⁿ ≡ f(x) − xⁿ (mod f)`, this is what replaces an overflowing `xⁿ`.
+    const REDUCTION: u128;
+}
+
+// -----------------------------------------------------------------------------
+// Definition of the generic binary field BinaryField<C>
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub struct BinaryField<C> {
+    _config: PhantomData<C>,
+}
+
+// Written by hand, since the derive would require `C: Default`.
+impl<C> Default for BinaryField<C> {
+    fn default() -> Self {
+        Self { _config: PhantomData }
+    }
+}
+
+impl<C: BinaryFieldConfig> BinaryField<C> {
+    /// Mask selecting the `n` coefficient bits.
+    const MASK: u128 = if C::DEGREE == 128 { u128::MAX } else { (1u128 << C::DEGREE) - 1 };
+
+    /// Returns `true` if `a` is a polynomial of degree below `n`.
+    pub fn contains(a: &u128) -> bool {
+        a & !Self::MASK == 0
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement AlgebraicStructure for BinaryField<C> (both for additive and multiplicative operations)
+// -----------------------------------------------------------------------------
+impl<C: BinaryFieldConfig> AlgebraicStructure<Additive> for BinaryField<C> {
+    type Element = u128;
+}
+
+impl<C: BinaryFieldConfig> AlgebraicStructure<Multiplicative> for BinaryField<C> {
+    type Element = u128;
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for addition on BinaryField<C> elements
+// (Coefficients live in 𝔽₂, so addition is XOR)
+// -----------------------------------------------------------------------------
+impl<C: BinaryFieldConfig> BinaryOperation<Additive, BinaryField<C>> for u128 {
+    fn op(a: &u128, b: &u128) -> u128 {
+        a ^ b
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for multiplication on BinaryField<C> elements
+// (Carry-less shift-and-xor multiplication, reducing by f(x) on the fly)
+// -----------------------------------------------------------------------------
+impl<C: BinaryFieldConfig> BinaryOperation<Multiplicative, BinaryField<C>> for u128 {
+    fn op(a: &u128, b: &u128) -> u128 {
+        let mut a = *a;
+        let mut b = *b;
+        let mut result = 0u128;
+        for _ in 0..C::DEGREE {
+            // result += a when the lowest bit of b is set (masks, no branches)
+            result ^= a & (b & 1).wrapping_neg();
+            b >>= 1;
+            // a = a·x mod f: shift, and fold the xⁿ term back in if it appeared
+            let overflow = (a >> (C::DEGREE - 1)) & 1;
+            a = (a << 1) & BinaryField::<C>::MASK;
+            a ^= C::REDUCTION & overflow.wrapping_neg();
+        }
+        result
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement the Finite trait for BinaryField<C>
+// -----------------------------------------------------------------------------
+impl<C: BinaryFieldConfig> Finite for BinaryField<C> {
+    /// Returns the order of the field, which is 2ⁿ.
+    fn order(&self) -> U256 {
+        U256::one() << C::DEGREE as usize
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Field and FiniteField implementations for BinaryField<C>
+// -----------------------------------------------------------------------------
+impl<C: BinaryFieldConfig> Field<u128> for BinaryField<C> {}
+impl<C: BinaryFieldConfig> FiniteField<u128> for BinaryField<C> {}
+
+// -----------------------------------------------------------------------------
+// Distributive, Commutative, Group, and Associative trait implementations
+// -----------------------------------------------------------------------------
+impl<C: BinaryFieldConfig> Distributive<BinaryField<C>, u128> for BinaryField<C> {}
+impl<C: BinaryFieldConfig> Distributive<BinaryField<C>, u128> for u128 {}
+
+impl<C: BinaryFieldConfig> Commutative<Additive, BinaryField<C>> for u128 {}
+impl<C: BinaryFieldConfig> Commutative<Multiplicative, BinaryField<C>> for u128 {}
+
+impl<C: BinaryFieldConfig> Group<Additive> for BinaryField<C> {}
+impl<C: BinaryFieldConfig> Group<Multiplicative> for BinaryField<C> {}
+
+impl<C: BinaryFieldConfig> Associative<Additive, BinaryField<C>> for u128 {}
+impl<C: BinaryFieldConfig> Associative<Multiplicative, BinaryField<C>> for u128 {}
+
+impl<C: BinaryFieldConfig> GroupOps<Additive, BinaryField<C>> for u128 {}
+impl<C: BinaryFieldConfig> GroupOps<Multiplicative, BinaryField<C>> for u128 {}
+
+// -----------------------------------------------------------------------------
+// Implement Invertible trait for finding multiplicative and additive inverses.
+// -----------------------------------------------------------------------------
+impl<C: BinaryFieldConfig> Invertible<Multiplicative, BinaryField<C>> for u128 {
+    /// Computes the multiplicative inverse as a^(2ⁿ − 2),
+    /// since the multiplicative group has order 2ⁿ − 1.
+    /// Returns None if a is zero.
+    fn inverse(a: &u128) -> Option<u128> {
+        if *a == 0 {
+            return None;
+        }
+        let exp = (U256::one() << C::DEGREE as usize) - 2;
+        Some(<BinaryField<C> as Power<Multiplicative, BinaryField<C>>>::pow(a, exp))
+    }
+}
+
+impl<C: BinaryFieldConfig> Invertible<Additive, BinaryField<C>> for u128 {
+    /// In characteristic 2 every element is its own additive inverse.
+    fn inverse(a: &u128) -> Option<u128> {
+        Some(*a)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement Identity trait to provide the neutral elements.
+// -----------------------------------------------------------------------------
+impl<C: BinaryFieldConfig> Identity<Multiplicative, BinaryField<C>> for BinaryField<C> {
+    /// Returns the multiplicative identity, the constant polynomial 1.
+    fn identity() -> u128 {
+        1
+    }
+}
+
+impl<C: BinaryFieldConfig> Identity<Additive, BinaryField<C>> for BinaryField<C> {
+    /// Returns the additive identity, the zero polynomial.
+    fn identity() -> u128 {
+        0
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use primitive_types::U256;
+
+use crate::algebra::{
+    group::{element::GroupOps, extension::Power},
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{Commutative, Distributive, Identity, Invertible},
+};
+
+use super::{
+    fp::{FieldModulus, Fp},
+    operation_helpers::{add, mul, sub},
+    Field,
+};
+
+/// An element of the field `F`, carrying its field in the type.
+///
+/// The raw element type `T` (e.g. `U256`) is shared by many fields, so a bare
+/// value says nothing about which modulus it belongs to. Wrapping it in
+/// `FieldElement<F, T>` makes mixing elements of different fields a type error
+/// and enables the usual arithmetic operators, all of which delegate to the
+/// `BinaryOperation` and `Invertible` implementations of `F`.
+pub struct FieldElement<F, T = U256> {
+    value: T,
+    _field: PhantomData<F>,
+}
+
+impl<F, T> FieldElement<F, T> {
+    /// Wraps a raw value, which must already be a canonical representative of `F`.
+    pub fn new(value: T) -> Self {
+        Self { value, _field: PhantomData }
+    }
+
+    /// Returns a reference to the raw value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Unwraps the raw value.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<F: Field<T>, T> FieldElement<F, T>
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    /// Returns the additive identity of `F`.
+    pub fn zero() -> Self {
+        Self::new(<F as Identity<Additive, F>>::identity())
+    }
+
+    /// Returns the multiplicative identity of `F`.
+    pub fn one() -> Self {
+        Self::new(<F as Identity<Multiplicative, F>>::identity())
+    }
+
+    /// Returns `true` if this is the additive identity.
+    pub fn is_zero(&self) -> bool {
+        self.value == <F as Identity<Additive, F>>::identity()
+    }
+
+    /// Returns the multiplicative inverse, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        <T as Invertible<Multiplicative, F>>::inverse(&self.value).map(Self::new)
+    }
+
+    /// Raises the element to the power `exp`.
+    pub fn pow(&self, exp: U256) -> Self {
+        Self::new(<F as Power<Multiplicative, F>>::pow(&self.value, exp))
+    }
+
+    /// Returns `self · self`.
+    pub fn square(&self) -> Self {
+        Self::new(mul::<F, T>(&self.value, &self.value))
+    }
+
+    /// Returns `self + self`.
+    pub fn double(&self) -> Self {
+        Self::new(add::<F, T>(&self.value, &self.value))
+    }
+}
+
+impl<M: FieldModulus> From<U256> for FieldElement<Fp<M>> {
+    /// Reduces an arbitrary `U256` modulo `p`.
+    fn from(value: U256) -> Self {
+        Self::new(value % M::MODULUS)
+    }
+}
+
+impl<M: FieldModulus> From<u64> for FieldElement<Fp<M>> {
+    fn from(value: u64) -> Self {
+        Self::from(U256::from(value))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Standard trait implementations (written by hand, since the field marker
+// types themselves implement none of Clone, Copy, PartialEq)
+// -----------------------------------------------------------------------------
+impl<F, T: Clone> Clone for FieldElement<F, T> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<F, T: Copy> Copy for FieldElement<F, T> {}
+
+impl<F, T: PartialEq> PartialEq for FieldElement<F, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<F, T: Eq> Eq for FieldElement<F, T> {}
+
+impl<F, T: fmt::Debug> fmt::Debug for FieldElement<F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FieldElement").field(&self.value).finish()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Arithmetic operators
+//
+// Each binary operator is implemented for owned values and references,
+// together with its assignment variant.
+// -----------------------------------------------------------------------------
+macro_rules! impl_binary_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:expr) => {
+        impl<F: Field<T>, T> $trait<&FieldElement<F, T>> for &FieldElement<F, T>
+        where
+            T: Clone
+                + GroupOps<Additive, F>
+                + GroupOps<Multiplicative, F>
+                + BinaryOperation<Additive, F>
+                + BinaryOperation<Multiplicative, F>
+                + Commutative<Additive, F>
+                + Commutative<Multiplicative, F>
+                + Distributive<F, T>,
+        {
+            type Output = FieldElement<F, T>;
+
+            fn $method(self, rhs: &FieldElement<F, T>) -> FieldElement<F, T> {
+                FieldElement::new($op(&self.value, &rhs.value))
+            }
+        }
+
+        impl<F: Field<T>, T> $trait for FieldElement<F, T>
+        where
+            T: Clone
+                + GroupOps<Additive, F>
+                + GroupOps<Multiplicative, F>
+                + BinaryOperation<Additive, F>
+                + BinaryOperation<Multiplicative, F>
+                + Commutative<Additive, F>
+                + Commutative<Multiplicative, F>
+                + Distributive<F, T>,
+        {
+            type Output = FieldElement<F, T>;
+
+            fn $method(self, rhs: FieldElement<F, T>) -> FieldElement<F, T> {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<F: Field<T>, T> $assign_trait for FieldElement<F, T>
+        where
+            T: Clone
+                + GroupOps<Additive, F>
+                + GroupOps<Multiplicative, F>
+                + BinaryOperation<Additive, F>
+                + BinaryOperation<Multiplicative, F>
+                + Commutative<Additive, F>
+                + Commutative<Multiplicative, F>
+                + Distributive<F, T>,
+        {
+            fn $assign_method(&mut self, rhs: FieldElement<F, T>) {
+                self.value = $op(&self.value, &rhs.value);
+            }
+        }
+    };
+}
+
+/// Division multiplies by the inverse of the divisor.
+///
+/// # Panics
+///
+/// Panics if the divisor is zero, mirroring integer division.
+fn div<F: Field<T>, T>(a: &T, b: &T) -> T
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    let inverse = <T as Invertible<Multiplicative, F>>::inverse(b)
+        .expect("division by zero in a field");
+    mul::<F, T>(a, &inverse)
+}
+
+impl_binary_operator!(Add, add, AddAssign, add_assign, add::<F, T>);
+impl_binary_operator!(Sub, sub, SubAssign, sub_assign, sub::<F, T>);
+impl_binary_operator!(Mul, mul, MulAssign, mul_assign, mul::<F, T>);
+impl_binary_operator!(Div, div, DivAssign, div_assign, div::<F, T>);
+
+impl<F: Field<T>, T> Neg for &FieldElement<F, T>
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    type Output = FieldElement<F, T>;
+
+    fn neg(self) -> FieldElement<F, T> {
+        let negated = <T as Invertible<Additive, F>>::inverse(&self.value)
+            .expect("every field element has an additive inverse");
+        FieldElement::new(negated)
+    }
+}
+
+impl<F: Field<T>, T> Neg for FieldElement<F, T>
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    type Output = FieldElement<F, T>;
+
+    fn neg(self) -> FieldElement<F, T> {
+        -&self
+    }
+}
@@ -0,0 +1,252 @@
+use primitive_types::U256;
+
+use crate::algebra::{
+    field::{operation_helpers, Field},
+    group::{element::GroupOps, extension::Power, Group},
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{Associative, Commutative, Distributive, Identity, Invertible},
+    AlgebraicStructure,
+};
+
+use super::Frobenius;
+
+/// Parameters of a **cubic extension** `F[v] / (v³ − ξ)` of a base field `F`.
+///
+/// `ξ` must be a cubic non-residue in `F`, so that `v³ − ξ` is irreducible.
+/// In the BN254 tower, `F = 𝔽ₚ²` and `ξ = 9 + u`, giving `𝔽ₚ⁶`.
+pub trait CubicExtensionConfig: std::fmt::Debug {
+    /// The element type of the base field.
+    type BaseElement: Clone
+        + std::fmt::Debug
+        + PartialEq
+        + BinaryOperation<Additive, Self::BaseField>
+        + BinaryOperation<Multiplicative, Self::BaseField>
+        + GroupOps<Additive, Self::BaseField>
+        + GroupOps<Multiplicative, Self::BaseField>
+        + Commutative<Additive, Self::BaseField>
+        + Commutative<Multiplicative, Self::BaseField>
+        + Distributive<Self::BaseField, Self::BaseElement>;
+
+    /// The base field `F`.
+    type BaseField: Field<Self::BaseElement> + Frobenius<Self::BaseElement>;
+
+    /// The non-residue `ξ = v³`.
+    fn non_residue() -> Self::BaseElement;
+
+    /// Multiplies a base field element by `ξ`.
+    ///
+    /// Override this when the product with `ξ` has a cheaper form.
+    fn mul_by_non_residue(a: &Self::BaseElement) -> Self::BaseElement {
+        mul::<Self>(a, &Self::non_residue())
+    }
+
+    /// Returns `γ = ξ^((p − 1) / 3)`, the factor with `vᵖ = γ·v`.
+    ///
+    /// Requires `p ≡ 1 (mod 3)`. The default computes it by exponentiation
+    /// on every call; configurations used in hot paths may return a
+    /// precomputed constant.
+    fn frobenius_coefficient() -> Self::BaseElement {
+        let exp = (<Self::BaseField as Frobenius<Self::BaseElement>>::CHARACTERISTIC - 1) / 3;
+        <Self::BaseField as Power<Multiplicative, Self::BaseField>>::pow(&Self::non_residue(), exp)
+    }
+}
+
+/// The cubic extension field described by `C`.
+#[derive(Debug)]
+pub struct CubicExtension<C> {
+    _config: std::marker::PhantomData<C>,
+}
+
+/// An element `c0 + c1·v + c2·v²` of a cubic extension.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CubicElement<T> {
+    pub c0: T,
+    pub c1: T,
+    pub c2: T,
+}
+
+impl<T> CubicElement<T> {
+    pub fn new(c0: T, c1: T, c2: T) -> Self {
+        Self { c0, c1, c2 }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Base field shorthands
+// -----------------------------------------------------------------------------
+fn add<C: CubicExtensionConfig + ?Sized>(a: &C::BaseElement, b: &C::BaseElement) -> C::BaseElement {
+    operation_helpers::add::<C::BaseField, C::BaseElement>(a, b)
+}
+
+fn sub<C: CubicExtensionConfig + ?Sized>(a: &C::BaseElement, b: &C::BaseElement) -> C::BaseElement {
+    operation_helpers::sub::<C::BaseField, C::BaseElement>(a, b)
+}
+
+fn mul<C: CubicExtensionConfig + ?Sized>(a: &C::BaseElement, b: &C::BaseElement) -> C::BaseElement {
+    operation_helpers::mul::<C::BaseField, C::BaseElement>(a, b)
+}
+
+fn neg<C: CubicExtensionConfig + ?Sized>(a: &C::BaseElement) -> C::BaseElement {
+    <C::BaseElement as Invertible<Additive, C::BaseField>>::inverse(a)
+        .expect("every field element has an additive inverse")
+}
+
+// -----------------------------------------------------------------------------
+// Extension-specific operations
+// -----------------------------------------------------------------------------
+impl<C: CubicExtensionConfig> CubicExtension<C> {
+    /// Multiplies every coefficient by an element of the base field.
+    pub fn mul_by_base(a: &CubicElement<C::BaseElement>, scalar: &C::BaseElement) -> CubicElement<C::BaseElement> {
+        CubicElement::new(
+            mul::<C>(&a.c0, scalar),
+            mul::<C>(&a.c1, scalar),
+            mul::<C>(&a.c2, scalar),
+        )
+    }
+}
+
+impl<C: CubicExtensionConfig> Frobenius<CubicElement<C::BaseElement>> for CubicExtension<C> {
+    const CHARACTERISTIC: U256 = <C::BaseField as Frobenius<C::BaseElement>>::CHARACTERISTIC;
+
+    /// Uses `(c0 + c1·v + c2·v²)ᵖ = c0ᵖ + c1ᵖ·γ·v + c2ᵖ·γ²·v²` with `γ = ξ^((p − 1) / 3)`.
+    fn frobenius_map(a: &CubicElement<C::BaseElement>, power: usize) -> CubicElement<C::BaseElement> {
+        let gamma = C::frobenius_coefficient();
+        let gamma_squared = mul::<C>(&gamma, &gamma);
+        let frobenius = |x: &C::BaseElement| <C::BaseField as Frobenius<C::BaseElement>>::frobenius_map(x, 1);
+
+        let mut result = a.clone();
+        for _ in 0..power {
+            result = CubicElement::new(
+                frobenius(&result.c0),
+                mul::<C>(&frobenius(&result.c1), &gamma),
+                mul::<C>(&frobenius(&result.c2), &gamma_squared),
+            );
+        }
+        result
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement AlgebraicStructure for CubicExtension<C>
+// -----------------------------------------------------------------------------
+impl<C: CubicExtensionConfig> AlgebraicStructure<Additive> for CubicExtension<C> {
+    type Element = CubicElement<C::BaseElement>;
+}
+
+impl<C: CubicExtensionConfig> AlgebraicStructure<Multiplicative> for CubicExtension<C> {
+    type Element = CubicElement<C::BaseElement>;
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for addition (coefficient-wise)
+// -----------------------------------------------------------------------------
+impl<C: CubicExtensionConfig> BinaryOperation<Additive, CubicExtension<C>> for CubicElement<C::BaseElement> {
+    fn op(a: &Self, b: &Self) -> Self {
+        CubicElement::new(
+            add::<C>(&a.c0, &b.c0),
+            add::<C>(&a.c1, &b.c1),
+            add::<C>(&a.c2, &b.c2),
+        )
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for multiplication, reducing with v³ = ξ:
+// c0 = a0·b0 + ξ·(a1·b2 + a2·b1)
+// c1 = a0·b1 + a1·b0 + ξ·a2·b2
+// c2 = a0·b2 + a1·b1 + a2·b0
+// -----------------------------------------------------------------------------
+impl<C: CubicExtensionConfig> BinaryOperation<Multiplicative, CubicExtension<C>> for CubicElement<C::BaseElement> {
+    fn op(a: &Self, b: &Self) -> Self {
+        let c0 = add::<C>(
+            &mul::<C>(&a.c0, &b.c0),
+            &C::mul_by_non_residue(&add::<C>(&mul::<C>(&a.c1, &b.c2), &mul::<C>(&a.c2, &b.c1))),
+        );
+        let c1 = add::<C>(
+            &add::<C>(&mul::<C>(&a.c0, &b.c1), &mul::<C>(&a.c1, &b.c0)),
+            &C::mul_by_non_residue(&mul::<C>(&a.c2, &b.c2)),
+        );
+        let c2 = add::<C>(
+            &add::<C>(&mul::<C>(&a.c0, &b.c2), &mul::<C>(&a.c1, &b.c1)),
+            &mul::<C>(&a.c2, &b.c0),
+        );
+        CubicElement::new(c0, c1, c2)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Field, Distributive, Commutative, Group, and Associative trait implementations
+// -----------------------------------------------------------------------------
+impl<C: CubicExtensionConfig> Field<CubicElement<C::BaseElement>> for CubicExtension<C> {}
+
+impl<C: CubicExtensionConfig> Distributive<CubicExtension<C>, CubicElement<C::BaseElement>>
+    for CubicElement<C::BaseElement>
+{
+}
+
+impl<C: CubicExtensionConfig> Commutative<Additive, CubicExtension<C>> for CubicElement<C::BaseElement> {}
+impl<C: CubicExtensionConfig> Commutative<Multiplicative, CubicExtension<C>> for CubicElement<C::BaseElement> {}
+
+impl<C: CubicExtensionConfig> Group<Additive> for CubicExtension<C> {}
+impl<C: CubicExtensionConfig> Group<Multiplicative> for CubicExtension<C> {}
+
+impl<C: CubicExtensionConfig> Associative<Additive, CubicExtension<C>> for CubicElement<C::BaseElement> {}
+impl<C: CubicExtensionConfig> Associative<Multiplicative, CubicExtension<C>> for CubicElement<C::BaseElement> {}
+
+impl<C: CubicExtensionConfig> GroupOps<Additive, CubicExtension<C>> for CubicElement<C::BaseElement> {}
+impl<C: CubicExtensionConfig> GroupOps<Multiplicative, CubicExtension<C>> for CubicElement<C::BaseElement> {}
+
+// -----------------------------------------------------------------------------
+// Implement Invertible trait for finding multiplicative and additive inverses.
+// -----------------------------------------------------------------------------
+impl<C: CubicExtensionConfig> Invertible<Multiplicative, CubicExtension<C>> for CubicElement<C::BaseElement> {
+    /// Computes the inverse through the adjugate, inverting a single
+    /// base field element:
+    /// t0 = a0² − ξ·a1·a2, t1 = ξ·a2² − a0·a1, t2 = a1² − a0·a2,
+    /// a⁻¹ = (t0 + t1·v + t2·v²) / (a0·t0 + ξ·(a2·t1 + a1·t2)).
+    /// Returns None if a is zero.
+    fn inverse(a: &Self) -> Option<Self> {
+        let t0 = sub::<C>(&mul::<C>(&a.c0, &a.c0), &C::mul_by_non_residue(&mul::<C>(&a.c1, &a.c2)));
+        let t1 = sub::<C>(&C::mul_by_non_residue(&mul::<C>(&a.c2, &a.c2)), &mul::<C>(&a.c0, &a.c1));
+        let t2 = sub::<C>(&mul::<C>(&a.c1, &a.c1), &mul::<C>(&a.c0, &a.c2));
+        let denominator = add::<C>(
+            &mul::<C>(&a.c0, &t0),
+            &C::mul_by_non_residue(&add::<C>(&mul::<C>(&a.c2, &t1), &mul::<C>(&a.c1, &t2))),
+        );
+        let denominator_inv =
+            <C::BaseElement as Invertible<Multiplicative, C::BaseField>>::inverse(&denominator)?;
+        Some(CubicExtension::<C>::mul_by_base(
+            &CubicElement::new(t0, t1, t2),
+            &denominator_inv,
+        ))
+    }
+}
+
+impl<C: CubicExtensionConfig> Invertible<Additive, CubicExtension<C>> for CubicElement<C::BaseElement> {
+    fn inverse(a: &Self) -> Option<Self> {
+        Some(CubicElement::new(neg::<C>(&a.c0), neg::<C>(&a.c1), neg::<C>(&a.c2)))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement Identity trait to provide the neutral elements.
+// -----------------------------------------------------------------------------
+impl<C: CubicExtensionConfig> Identity<Multiplicative, CubicExtension<C>> for CubicExtension<C> {
+    /// Returns the multiplicative identity `1 + 0·v + 0·v²`.
+    fn identity() -> CubicElement<C::BaseElement> {
+        let zero = <C::BaseField as Identity<Additive, C::BaseField>>::identity();
+        CubicElement::new(
+            <C::BaseField as Identity<Multiplicative, C::BaseField>>::identity(),
+            zero.clone(),
+            zero,
+        )
+    }
+}
+
+impl<C: CubicExtensionConfig> Identity<Additive, CubicExtension<C>> for CubicExtension<C> {
+    /// Returns the additive identity `0 + 0·v + 0·v²`.
+    fn identity() -> CubicElement<C::BaseElement> {
+        let zero = <C::BaseField as Identity<Additive, C::BaseField>>::identity();
+        CubicElement::new(zero.clone(), zero.clone(), zero)
+    }
+}
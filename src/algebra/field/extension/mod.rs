@@ -0,0 +1,29 @@
+use primitive_types::U256;
+
+use super::fp::{FieldModulus, Fp};
+
+pub mod cubic;
+pub mod quadratic;
+
+/// A trait for fields of characteristic `p` supporting the
+/// **Frobenius endomorphism** `x ↦ xᵖ`.
+///
+/// Prime fields are fixed pointwise by it; in an extension field it permutes
+/// the coordinates of an element, which is what makes it cheap to evaluate
+/// without a full exponentiation.
+pub trait Frobenius<T> {
+    /// The characteristic `p` of the field.
+    const CHARACTERISTIC: U256;
+
+    /// Applies the Frobenius endomorphism `power` times, i.e. computes `a^(p^power)`.
+    fn frobenius_map(a: &T, power: usize) -> T;
+}
+
+impl<M: FieldModulus> Frobenius<U256> for Fp<M> {
+    const CHARACTERISTIC: U256 = M::MODULUS;
+
+    /// Every element of 𝔽ₚ satisfies `aᵖ = a`.
+    fn frobenius_map(a: &U256, _power: usize) -> U256 {
+        *a
+    }
+}
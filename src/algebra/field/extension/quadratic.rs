@@ -0,0 +1,250 @@
+use primitive_types::U256;
+
+use crate::algebra::{
+    field::{operation_helpers, Field},
+    group::{element::GroupOps, extension::Power, Group},
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{Associative, Commutative, Distributive, Identity, Invertible},
+    AlgebraicStructure,
+};
+
+use super::Frobenius;
+
+/// Parameters of a **quadratic extension** `F[u] / (u² − β)` of a base field `F`.
+///
+/// `β` must be a quadratic non-residue in `F`, so that `u² − β` is irreducible.
+/// The base field may itself be an extension, which is how towers such as
+/// `𝔽ₚ² → 𝔽ₚ⁶ → 𝔽ₚ¹²` are assembled.
+pub trait QuadraticExtensionConfig: std::fmt::Debug {
+    /// The element type of the base field.
+    type BaseElement: Clone
+        + std::fmt::Debug
+        + PartialEq
+        + BinaryOperation<Additive, Self::BaseField>
+        + BinaryOperation<Multiplicative, Self::BaseField>
+        + GroupOps<Additive, Self::BaseField>
+        + GroupOps<Multiplicative, Self::BaseField>
+        + Commutative<Additive, Self::BaseField>
+        + Commutative<Multiplicative, Self::BaseField>
+        + Distributive<Self::BaseField, Self::BaseElement>;
+
+    /// The base field `F`.
+    type BaseField: Field<Self::BaseElement> + Frobenius<Self::BaseElement>;
+
+    /// The non-residue `β = u²`.
+    fn non_residue() -> Self::BaseElement;
+
+    /// Multiplies a base field element by `β`.
+    ///
+    /// Override this when `β` is small (e.g. `−1`) and the product has a cheaper form.
+    fn mul_by_non_residue(a: &Self::BaseElement) -> Self::BaseElement {
+        mul::<Self>(a, &Self::non_residue())
+    }
+
+    /// Returns `γ = β^((p − 1) / 2)`, the factor with `uᵖ = γ·u`.
+    ///
+    /// The default computes it by exponentiation on every call;
+    /// configurations used in hot paths may return a precomputed constant.
+    fn frobenius_coefficient() -> Self::BaseElement {
+        let exp = (<Self::BaseField as Frobenius<Self::BaseElement>>::CHARACTERISTIC - 1) >> 1;
+        <Self::BaseField as Power<Multiplicative, Self::BaseField>>::pow(&Self::non_residue(), exp)
+    }
+}
+
+/// The quadratic extension field described by `C`.
+#[derive(Debug)]
+pub struct QuadraticExtension<C> {
+    _config: std::marker::PhantomData<C>,
+}
+
+/// An element `c0 + c1·u` of a quadratic extension.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuadraticElement<T> {
+    pub c0: T,
+    pub c1: T,
+}
+
+impl<T> QuadraticElement<T> {
+    pub fn new(c0: T, c1: T) -> Self {
+        Self { c0, c1 }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Base field shorthands
+// -----------------------------------------------------------------------------
+fn add<C: QuadraticExtensionConfig + ?Sized>(a: &C::BaseElement, b: &C::BaseElement) -> C::BaseElement {
+    operation_helpers::add::<C::BaseField, C::BaseElement>(a, b)
+}
+
+fn sub<C: QuadraticExtensionConfig + ?Sized>(a: &C::BaseElement, b: &C::BaseElement) -> C::BaseElement {
+    operation_helpers::sub::<C::BaseField, C::BaseElement>(a, b)
+}
+
+fn mul<C: QuadraticExtensionConfig + ?Sized>(a: &C::BaseElement, b: &C::BaseElement) -> C::BaseElement {
+    operation_helpers::mul::<C::BaseField, C::BaseElement>(a, b)
+}
+
+fn neg<C: QuadraticExtensionConfig + ?Sized>(a: &C::BaseElement) -> C::BaseElement {
+    <C::BaseElement as Invertible<Additive, C::BaseField>>::inverse(a)
+        .expect("every field element has an additive inverse")
+}
+
+// -----------------------------------------------------------------------------
+// Extension-specific operations
+// -----------------------------------------------------------------------------
+impl<C: QuadraticExtensionConfig> QuadraticExtension<C> {
+    /// Returns the conjugate `c0 − c1·u`, the image of `a` under the
+    /// nontrivial automorphism fixing the base field.
+    pub fn conjugate(a: &QuadraticElement<C::BaseElement>) -> QuadraticElement<C::BaseElement> {
+        QuadraticElement::new(a.c0.clone(), neg::<C>(&a.c1))
+    }
+
+    /// Returns the norm `a·ā = c0² − β·c1²`, an element of the base field.
+    pub fn norm(a: &QuadraticElement<C::BaseElement>) -> C::BaseElement {
+        sub::<C>(
+            &mul::<C>(&a.c0, &a.c0),
+            &C::mul_by_non_residue(&mul::<C>(&a.c1, &a.c1)),
+        )
+    }
+
+    /// Multiplies every coefficient by an element of the base field.
+    pub fn mul_by_base(
+        a: &QuadraticElement<C::BaseElement>,
+        scalar: &C::BaseElement,
+    ) -> QuadraticElement<C::BaseElement> {
+        QuadraticElement::new(mul::<C>(&a.c0, scalar), mul::<C>(&a.c1, scalar))
+    }
+}
+
+impl<C: QuadraticExtensionConfig> Frobenius<QuadraticElement<C::BaseElement>> for QuadraticExtension<C> {
+    const CHARACTERISTIC: U256 = <C::BaseField as Frobenius<C::BaseElement>>::CHARACTERISTIC;
+
+    /// Uses `(c0 + c1·u)ᵖ = c0ᵖ + c1ᵖ·γ·u` with `γ = β^((p − 1) / 2)`.
+    fn frobenius_map(a: &QuadraticElement<C::BaseElement>, power: usize) -> QuadraticElement<C::BaseElement> {
+        let gamma = C::frobenius_coefficient();
+        let mut result = a.clone();
+        for _ in 0..power {
+            result = QuadraticElement::new(
+                <C::BaseField as Frobenius<C::BaseElement>>::frobenius_map(&result.c0, 1),
+                mul::<C>(
+                    &<C::BaseField as Frobenius<C::BaseElement>>::frobenius_map(&result.c1, 1),
+                    &gamma,
+                ),
+            );
+        }
+        result
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement AlgebraicStructure for QuadraticExtension<C>
+// -----------------------------------------------------------------------------
+impl<C: QuadraticExtensionConfig> AlgebraicStructure<Additive> for QuadraticExtension<C> {
+    type Element = QuadraticElement<C::BaseElement>;
+}
+
+impl<C: QuadraticExtensionConfig> AlgebraicStructure<Multiplicative> for QuadraticExtension<C> {
+    type Element = QuadraticElement<C::BaseElement>;
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for addition (coefficient-wise)
+// -----------------------------------------------------------------------------
+impl<C: QuadraticExtensionConfig> BinaryOperation<Additive, QuadraticExtension<C>>
+    for QuadraticElement<C::BaseElement>
+{
+    fn op(a: &Self, b: &Self) -> Self {
+        QuadraticElement::new(add::<C>(&a.c0, &b.c0), add::<C>(&a.c1, &b.c1))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for multiplication (Karatsuba):
+// (a0 + a1·u)(b0 + b1·u) = a0·b0 + β·a1·b1 + ((a0 + a1)(b0 + b1) − a0·b0 − a1·b1)·u
+// -----------------------------------------------------------------------------
+impl<C: QuadraticExtensionConfig> BinaryOperation<Multiplicative, QuadraticExtension<C>>
+    for QuadraticElement<C::BaseElement>
+{
+    fn op(a: &Self, b: &Self) -> Self {
+        let v0 = mul::<C>(&a.c0, &b.c0);
+        let v1 = mul::<C>(&a.c1, &b.c1);
+        let cross = mul::<C>(&add::<C>(&a.c0, &a.c1), &add::<C>(&b.c0, &b.c1));
+        QuadraticElement::new(
+            add::<C>(&v0, &C::mul_by_non_residue(&v1)),
+            sub::<C>(&sub::<C>(&cross, &v0), &v1),
+        )
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Field, Distributive, Commutative, Group, and Associative trait implementations
+// -----------------------------------------------------------------------------
+impl<C: QuadraticExtensionConfig> Field<QuadraticElement<C::BaseElement>> for QuadraticExtension<C> {}
+
+impl<C: QuadraticExtensionConfig> Distributive<QuadraticExtension<C>, QuadraticElement<C::BaseElement>>
+    for QuadraticElement<C::BaseElement>
+{
+}
+
+impl<C: QuadraticExtensionConfig> Commutative<Additive, QuadraticExtension<C>> for QuadraticElement<C::BaseElement> {}
+impl<C: QuadraticExtensionConfig> Commutative<Multiplicative, QuadraticExtension<C>> for QuadraticElement<C::BaseElement> {}
+
+impl<C: QuadraticExtensionConfig> Group<Additive> for QuadraticExtension<C> {}
+impl<C: QuadraticExtensionConfig> Group<Multiplicative> for QuadraticExtension<C> {}
+
+impl<C: QuadraticExtensionConfig> Associative<Additive, QuadraticExtension<C>> for QuadraticElement<C::BaseElement> {}
+impl<C: QuadraticExtensionConfig> Associative<Multiplicative, QuadraticExtension<C>> for QuadraticElement<C::BaseElement> {}
+
+impl<C: QuadraticExtensionConfig> GroupOps<Additive, QuadraticExtension<C>> for QuadraticElement<C::BaseElement> {}
+impl<C: QuadraticExtensionConfig> GroupOps<Multiplicative, QuadraticExtension<C>> for QuadraticElement<C::BaseElement> {}
+
+// -----------------------------------------------------------------------------
+// Implement Invertible trait for finding multiplicative and additive inverses.
+// -----------------------------------------------------------------------------
+impl<C: QuadraticExtensionConfig> Invertible<Multiplicative, QuadraticExtension<C>>
+    for QuadraticElement<C::BaseElement>
+{
+    /// Computes `a⁻¹ = ā / N(a)`, inverting only the norm in the base field.
+    /// Returns None if a is zero.
+    fn inverse(a: &Self) -> Option<Self> {
+        let norm_inv = <C::BaseElement as Invertible<Multiplicative, C::BaseField>>::inverse(
+            &QuadraticExtension::<C>::norm(a),
+        )?;
+        Some(QuadraticExtension::<C>::mul_by_base(
+            &QuadraticExtension::<C>::conjugate(a),
+            &norm_inv,
+        ))
+    }
+}
+
+impl<C: QuadraticExtensionConfig> Invertible<Additive, QuadraticExtension<C>>
+    for QuadraticElement<C::BaseElement>
+{
+    fn inverse(a: &Self) -> Option<Self> {
+        Some(QuadraticElement::new(neg::<C>(&a.c0), neg::<C>(&a.c1)))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement Identity trait to provide the neutral elements.
+// -----------------------------------------------------------------------------
+impl<C: QuadraticExtensionConfig> Identity<Multiplicative, QuadraticExtension<C>> for QuadraticExtension<C> {
+    /// Returns the multiplicative identity `1 + 0·u`.
+    fn identity() -> QuadraticElement<C::BaseElement> {
+        QuadraticElement::new(
+            <C::BaseField as Identity<Multiplicative, C::BaseField>>::identity(),
+            <C::BaseField as Identity<Additive, C::BaseField>>::identity(),
+        )
+    }
+}
+
+impl<C: QuadraticExtensionConfig> Identity<Additive, QuadraticExtension<C>> for QuadraticExtension<C> {
+    /// Returns the additive identity `0 + 0·u`.
+    fn identity() -> QuadraticElement<C::BaseElement> {
+        QuadraticElement::new(
+            <C::BaseField as Identity<Additive, C::BaseField>>::identity(),
+            <C::BaseField as Identity<Additive, C::BaseField>>::identity(),
+        )
+    }
+}
@@ -0,0 +1,411 @@
+// -----------------------------------------------------------------------------
+// External and internal module imports
+// -----------------------------------------------------------------------------
+use std::marker::PhantomData;
+
+use modular_math::mod_math::ModMath;
+use primitive_types::U256;
+
+use crate::{
+    algebra::{
+        field::{montgomery, safegcd, Field, FiniteField},
+        group::{element::GroupOps, Group},
+        operations::{Additive, BinaryOperation, Multiplicative},
+        properties::{
+            Associative, Commutative, Distributive, Finite, Identity, Invertible,
+        },
+        AlgebraicStructure,
+    },
+    helpers::{add_mod, jacobi, sub_mod},
+};
+
+/// A trait describing the **prime modulus** of a prime field 𝔽ₚ.
+///
+/// Implementors are zero-sized marker types; the modulus itself lives in
+/// the associated constant, so every prime field shares the single generic
+/// implementation provided by [`Fp`].
+///
+/// The Montgomery constants are derived from `MODULUS` at compile time
+/// and only need to be overridden if they are already known.
+/// Montgomery arithmetic requires an odd modulus, i.e. any prime `p > 2`.
+pub trait FieldModulus: std::fmt::Debug {
+    /// The prime `p` defining the field 𝔽ₚ.
+    const MODULUS: U256;
+
+    /// `R = 2²⁵⁶ mod p`, the Montgomery form of `1`.
+    const R: U256 = U256(montgomery::r(&Self::MODULUS.0));
+
+    /// `R² mod p`, used to convert values into Montgomery form.
+    const R2: U256 = U256(montgomery::r2(&Self::MODULUS.0));
+
+    /// `-p⁻¹ mod 2⁶⁴`, used by Montgomery reduction.
+    const INV: u64 = montgomery::neg_inv(Self::MODULUS.0[0]);
+
+    /// Computes `a·b mod p` for canonical operands `a, b < p`.
+    ///
+    /// The default performs two Montgomery multiplications:
+    /// `(a·b·R⁻¹)·R²·R⁻¹ = a·b`, so no division is involved.
+    /// Long chains of multiplications should use [`Montgomery`] instead,
+    /// which stays in Montgomery form and pays one multiplication per step.
+    /// Moduli of a special shape may override it with a dedicated reduction.
+    fn mul(a: &U256, b: &U256) -> U256 {
+        let product = montgomery::montgomery_mul(a, b, &Self::MODULUS, Self::INV);
+        montgomery::montgomery_mul(&product, &Self::R2, &Self::MODULUS, Self::INV)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Definition of the generic prime field Fp<M> (Field of integers modulo M::MODULUS)
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub struct Fp<M> {
+    // The underlying value stored as a U256
+    pub value: U256,
+    _modulus: PhantomData<M>,
+}
+
+/// Declares a new prime field in one line.
+///
+/// Expands to a modulus marker type implementing [`FieldModulus`] and a type
+/// alias for the corresponding [`Fp`] instantiation:
+///
+/// ```ignore
+/// prime_field!(pub Fp_7, Fp7Modulus, U256([7, 0, 0, 0]));
+/// ```
+#[macro_export]
+macro_rules! prime_field {
+    ($vis:vis $name:ident, $modulus:ident, $value:expr) => {
+        #[derive(Debug)]
+        $vis struct $modulus;
+
+        impl $crate::algebra::field::fp::FieldModulus for $modulus {
+            const MODULUS: ::primitive_types::U256 = $value;
+        }
+
+        $vis type $name = $crate::algebra::field::fp::Fp<$modulus>;
+    };
+}
+
+// -----------------------------------------------------------------------------
+// Implement AlgebraicStructure for Fp<M> (both for additive and multiplicative operations)
+// -----------------------------------------------------------------------------
+impl<M: FieldModulus> AlgebraicStructure<Additive> for Fp<M> {
+    // Both operations use U256 as the element type
+    type Element = U256;
+}
+
+impl<M: FieldModulus> AlgebraicStructure<Multiplicative> for Fp<M> {
+    type Element = U256;
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for addition on Fp<M> elements
+// (Computes (a + b) mod p, detecting the carry out of 256 bits)
+// -----------------------------------------------------------------------------
+impl<M: FieldModulus> BinaryOperation<Additive, Fp<M>> for U256 {
+    fn op(
+        a: &<Fp<M> as AlgebraicStructure<Additive>>::Element,
+        b: &<Fp<M> as AlgebraicStructure<Additive>>::Element,
+    ) -> <Fp<M> as AlgebraicStructure<Additive>>::Element {
+        add_mod(*a, *b, M::MODULUS)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for multiplication on Fp<M> elements
+// (Computes (a * b) mod p using the reduction chosen by the modulus)
+// -----------------------------------------------------------------------------
+impl<M: FieldModulus> BinaryOperation<Multiplicative, Fp<M>> for U256 {
+    fn op(
+        a: &<Fp<M> as AlgebraicStructure<Multiplicative>>::Element,
+        b: &<Fp<M> as AlgebraicStructure<Multiplicative>>::Element,
+    ) -> <Fp<M> as AlgebraicStructure<Multiplicative>>::Element {
+        M::mul(&Fp::<M>::reduce(a), &Fp::<M>::reduce(b))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Constructor and helper methods for Fp<M>
+// -----------------------------------------------------------------------------
+impl<M: FieldModulus> Fp<M> {
+    /// Creates a new Fp element by applying modulo reduction to the given value.
+    pub fn new(value: U256) -> Self {
+        Self {
+            // Use ModMath utility to ensure the value is reduced modulo p
+            value: ModMath::new(M::MODULUS).modulus(value),
+            _modulus: PhantomData,
+        }
+    }
+
+    /// Brings an arbitrary `U256` into the canonical range `[0, p)`.
+    #[inline]
+    fn reduce(a: &U256) -> U256 {
+        if *a >= M::MODULUS { a % M::MODULUS } else { *a }
+    }
+
+    /// Converts a canonical element `a` into Montgomery form `a·R mod p`.
+    pub fn to_montgomery(a: &U256) -> U256 {
+        Self::montgomery_mul(&Self::reduce(a), &M::R2)
+    }
+
+    /// Converts an element `a·R mod p` out of Montgomery form back to `a`.
+    pub fn from_montgomery(a: &U256) -> U256 {
+        Self::montgomery_mul(a, &U256::one())
+    }
+
+    /// Multiplies two elements given in Montgomery form,
+    /// returning the product in Montgomery form.
+    #[inline]
+    pub fn montgomery_mul(a: &U256, b: &U256) -> U256 {
+        montgomery::montgomery_mul(a, b, &M::MODULUS, M::INV)
+    }
+
+    /// Computes `a⁻¹ mod p` in constant time using Bernstein–Yang divsteps.
+    ///
+    /// Returns `0` for `a = 0`, which has no inverse.
+    pub fn inverse_ct(a: &U256) -> U256 {
+        safegcd::inverse(&Self::reduce(a), &M::MODULUS)
+    }
+
+    /// Computes the Legendre symbol `(a / p)`.
+    ///
+    /// Returns `1` if `a` is a nonzero quadratic residue, `-1` if it is a
+    /// non-residue, and `0` if `a ≡ 0 (mod p)`.
+    pub fn legendre(a: &U256) -> i8 {
+        jacobi(*a, M::MODULUS)
+    }
+
+    /// Returns `true` if `a` is a square in the field (zero included),
+    /// without computing the square root itself.
+    pub fn is_quadratic_residue(a: &U256) -> bool {
+        Self::legendre(a) >= 0
+    }
+
+    /// Computes `base^exp mod p` by square-and-multiply,
+    /// keeping all intermediate values in Montgomery form.
+    pub fn pow(base: &U256, exp: &U256) -> U256 {
+        Montgomery::<M>::new(base).pow(exp).value()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Definition of Montgomery<M> (elements of Fp<M> kept in Montgomery form)
+// -----------------------------------------------------------------------------
+
+/// An element of 𝔽ₚ stored in Montgomery form `a·R mod p`.
+///
+/// The canonical `U256` elements of [`Fp`] have to be converted in and out of
+/// Montgomery form by every multiplication. `Montgomery<M>` converts once when
+/// created with [`new`](Self::new) and once when read back with
+/// [`value`](Self::value); in between, each multiplication is a single
+/// `montgomery_mul` and additions work on the representation directly.
+pub struct Montgomery<M> {
+    repr: U256,
+    _modulus: PhantomData<M>,
+}
+
+impl<M: FieldModulus> Montgomery<M> {
+    /// Converts a canonical (or unreduced) value into Montgomery form.
+    pub fn new(value: &U256) -> Self {
+        Self::from_repr(Fp::<M>::to_montgomery(value))
+    }
+
+    /// Wraps a value that is already in Montgomery form.
+    pub fn from_repr(repr: U256) -> Self {
+        Self { repr, _modulus: PhantomData }
+    }
+
+    /// Returns the Montgomery form `a·R mod p`.
+    pub fn repr(&self) -> U256 {
+        self.repr
+    }
+
+    /// Converts back to the canonical value `a`.
+    pub fn value(&self) -> U256 {
+        Fp::<M>::from_montgomery(&self.repr)
+    }
+
+    pub fn zero() -> Self {
+        Self::from_repr(U256::zero())
+    }
+
+    /// Returns `1`, whose Montgomery form is `R mod p`.
+    pub fn one() -> Self {
+        Self::from_repr(M::R)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.repr.is_zero()
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    /// Computes `self^exp` by square-and-multiply.
+    pub fn pow(&self, exp: &U256) -> Self {
+        let mut acc = Self::one();
+        for i in (0..exp.bits()).rev() {
+            acc = acc.square();
+            if exp.bit(i) {
+                acc = acc * *self;
+            }
+        }
+        acc
+    }
+
+    /// Returns the multiplicative inverse, or None for zero.
+    ///
+    /// The constant-time inversion of `a·R` yields `a⁻¹·R⁻¹`;
+    /// two multiplications by `R²` bring it back to `a⁻¹·R`.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        let inv = Fp::<M>::inverse_ct(&self.repr);
+        Some(Self::from_repr(Fp::<M>::montgomery_mul(
+            &Fp::<M>::montgomery_mul(&inv, &M::R2),
+            &M::R2,
+        )))
+    }
+}
+
+// Written by hand, since the derives would require bounds on `M`.
+impl<M> Clone for Montgomery<M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M> Copy for Montgomery<M> {}
+
+impl<M> PartialEq for Montgomery<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.repr == other.repr
+    }
+}
+
+impl<M> Eq for Montgomery<M> {}
+
+impl<M> std::fmt::Debug for Montgomery<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Montgomery").field(&self.repr).finish()
+    }
+}
+
+// Addition and negation commute with the scaling by R, so they act on the
+// representation as usual; only multiplication needs Montgomery reduction.
+impl<M: FieldModulus> std::ops::Add for Montgomery<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_repr(add_mod(self.repr, rhs.repr, M::MODULUS))
+    }
+}
+
+impl<M: FieldModulus> std::ops::Sub for Montgomery<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_repr(sub_mod(self.repr, rhs.repr, M::MODULUS))
+    }
+}
+
+impl<M: FieldModulus> std::ops::Mul for Montgomery<M> {
+    type Output = Self;
+
+    /// `(a·R)·(b·R)·R⁻¹ = (a·b)·R`
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_repr(Fp::<M>::montgomery_mul(&self.repr, &rhs.repr))
+    }
+}
+
+impl<M: FieldModulus> std::ops::Neg for Montgomery<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_repr(sub_mod(U256::zero(), self.repr, M::MODULUS))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement the Finite trait for Fp<M>
+// -----------------------------------------------------------------------------
+impl<M: FieldModulus> Finite for Fp<M> {
+    /// Returns the order of the finite field, which is the modulus p.
+    fn order(&self) -> U256 {
+        M::MODULUS
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Field and FiniteField implementations for Fp<M>
+// -----------------------------------------------------------------------------
+impl<M: FieldModulus> Field<U256> for Fp<M> {}
+impl<M: FieldModulus> FiniteField<U256> for Fp<M> {}
+
+// -----------------------------------------------------------------------------
+// Distributive, Commutative, Group, and Associative trait implementations
+// These implementations are marked for both Fp<M> and U256 as needed.
+// -----------------------------------------------------------------------------
+impl<M: FieldModulus> Distributive<Fp<M>, U256> for Fp<M> {}
+impl<M: FieldModulus> Distributive<Fp<M>, U256> for U256 {}
+
+impl<M: FieldModulus> Commutative<Additive, Fp<M>> for U256 {}
+impl<M: FieldModulus> Commutative<Multiplicative, Fp<M>> for U256 {}
+
+impl<M: FieldModulus> Group<Additive> for Fp<M> {}
+impl<M: FieldModulus> Group<Multiplicative> for Fp<M> {}
+
+impl<M: FieldModulus> Associative<Additive, Fp<M>> for U256 {}
+impl<M: FieldModulus> Associative<Multiplicative, Fp<M>> for U256 {}
+
+impl<M: FieldModulus> GroupOps<Additive, Fp<M>> for U256 {}
+impl<M: FieldModulus> GroupOps<Multiplicative, Fp<M>> for U256 {}
+
+// -----------------------------------------------------------------------------
+// Implement Invertible trait for finding multiplicative and additive inverses.
+// -----------------------------------------------------------------------------
+impl<M: FieldModulus> Invertible<Multiplicative, Fp<M>> for U256 {
+    /// Computes the multiplicative inverse of an element a in Fp<M>.
+    /// Returns None if a is zero modulo p.
+    fn inverse(
+        a: &<Fp<M> as AlgebraicStructure<Multiplicative>>::Element,
+    ) -> Option<<Fp<M> as AlgebraicStructure<Multiplicative>>::Element> {
+        // Reduce first, so that non-canonical zeros (p, 2p, …) are rejected too
+        let a = Fp::<M>::reduce(a);
+        if a.is_zero() {
+            return None;
+        }
+        // Use the constant-time safegcd inversion, so secret elements
+        // do not influence the control flow
+        Some(Fp::<M>::inverse_ct(&a))
+    }
+}
+
+impl<M: FieldModulus> Invertible<Additive, Fp<M>> for U256 {
+    /// Computes the additive inverse of an element a in Fp<M>.
+    /// Conventionally, the additive inverse is defined as (p - a) mod p.
+    fn inverse(
+        a: &<Fp<M> as AlgebraicStructure<Additive>>::Element,
+    ) -> Option<<Fp<M> as AlgebraicStructure<Additive>>::Element> {
+        Some(sub_mod(U256::zero(), *a, M::MODULUS))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement Identity trait to provide the neutral elements.
+// -----------------------------------------------------------------------------
+impl<M: FieldModulus> Identity<Multiplicative, Fp<M>> for Fp<M> {
+    /// Returns the multiplicative identity (1) in Fp<M>.
+    fn identity() -> U256 {
+        U256::one()
+    }
+}
+
+impl<M: FieldModulus> Identity<Additive, Fp<M>> for Fp<M> {
+    /// Returns the additive identity (0) in Fp<M>.
+    fn identity() -> U256 {
+        U256::zero()
+    }
+}
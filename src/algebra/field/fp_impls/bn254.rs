@@ -0,0 +1,179 @@
+#![allow(non_camel_case_types)]
+
+use primitive_types::U256;
+
+use crate::algebra::{
+    field::{
+        extension::{
+            cubic::{CubicElement, CubicExtension, CubicExtensionConfig},
+            quadratic::{QuadraticElement, QuadraticExtension, QuadraticExtensionConfig},
+        },
+        fp::FieldModulus,
+        TwoAdicField,
+    },
+    operations::Additive,
+    properties::Invertible,
+};
+
+/// The BN254 base field prime.
+pub const P: U256 = U256([
+    0x3C208C16D87CFD47,
+    0x97816A916871CA8D,
+    0xB85045B68181585D,
+    0x30644E72E131A029,
+]);
+
+/// The BN254 scalar field prime, i.e. the order of the curve's prime-order subgroup.
+pub const R: U256 = U256([
+    0x43E1F593F0000001,
+    0x2833E84879B97091,
+    0xB85045B68181585D,
+    0x30644E72E131A029,
+]);
+
+// -----------------------------------------------------------------------------
+// Definition of the BN254 scalar field 𝔽ᵣ
+// (r − 1 is divisible by 2²⁸, so it supports NTTs of length up to 2²⁸)
+// -----------------------------------------------------------------------------
+crate::prime_field!(pub FR_BN254, Bn254ScalarModulus, R);
+
+impl TwoAdicField<U256> for FR_BN254 {
+    const TWO_ADICITY: u32 = 28;
+
+    /// 5 generates the multiplicative group of the BN254 scalar field.
+    fn multiplicative_generator() -> U256 {
+        U256::from(5)
+    }
+
+    /// Returns `5^((r − 1) / 2²⁸)`.
+    fn two_adic_generator() -> U256 {
+        let exp = (Bn254ScalarModulus::MODULUS - 1) >> Self::TWO_ADICITY as usize;
+        FR_BN254::pow(&Self::multiplicative_generator(), &exp)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Definition of the BN254 base field and its extension tower
+//   𝔽ₚ²  = 𝔽ₚ[u]  / (u² + 1)
+//   𝔽ₚ⁶  = 𝔽ₚ²[v] / (v³ − (9 + u))
+//   𝔽ₚ¹² = 𝔽ₚ⁶[w] / (w² − v)
+// -----------------------------------------------------------------------------
+crate::prime_field!(pub FP_BN254, Bn254Modulus, P);
+
+// Precomputed Frobenius coefficients, so that `frobenius_map`
+// does not exponentiate in 𝔽ₚ² on every call.
+
+/// ξ^((p − 1) / 3), real part.
+const FROBENIUS_FP6_C0: U256 = U256([
+    0x99E39557176F553D,
+    0xB78CC310C2C3330C,
+    0x4C0BEC3CF559B143,
+    0x2FB347984F7911F7,
+]);
+
+/// ξ^((p − 1) / 3), coefficient of u.
+const FROBENIUS_FP6_C1: U256 = U256([
+    0x1665D51C640FCBA2,
+    0x32AE2A1D0B7C9DCE,
+    0x4BA4CC8BD75A0794,
+    0x16C9E55061EBAE20,
+]);
+
+/// ξ^((p − 1) / 6), real part.
+const FROBENIUS_FP12_C0: U256 = U256([
+    0xD60B35DADCC9E470,
+    0x5C521E08292F2176,
+    0xE8B99FDD76E68B60,
+    0x1284B71C2865A7DF,
+]);
+
+/// ξ^((p − 1) / 6), coefficient of u.
+const FROBENIUS_FP12_C1: U256 = U256([
+    0xCA5CF05F80F362AC,
+    0x747992778EEEC7E5,
+    0xA6327CFE12150B8E,
+    0x246996F3B4FAE7E6,
+]);
+
+pub type FP2_BN254 = QuadraticExtension<Bn254Fp2Config>;
+pub type FP6_BN254 = CubicExtension<Bn254Fp6Config>;
+pub type FP12_BN254 = QuadraticExtension<Bn254Fp12Config>;
+
+pub type Fp2Element = QuadraticElement<U256>;
+pub type Fp6Element = CubicElement<Fp2Element>;
+pub type Fp12Element = QuadraticElement<Fp6Element>;
+
+#[derive(Debug)]
+pub struct Bn254Fp2Config;
+
+impl QuadraticExtensionConfig for Bn254Fp2Config {
+    type BaseElement = U256;
+    type BaseField = FP_BN254;
+
+    /// β = −1
+    fn non_residue() -> U256 {
+        P - U256::one()
+    }
+
+    fn mul_by_non_residue(a: &U256) -> U256 {
+        <U256 as Invertible<Additive, FP_BN254>>::inverse(a).unwrap()
+    }
+
+    /// γ = (−1)^((p − 1) / 2) = −1, since p ≡ 3 (mod 4)
+    fn frobenius_coefficient() -> U256 {
+        P - U256::one()
+    }
+}
+
+#[derive(Debug)]
+pub struct Bn254Fp6Config;
+
+impl CubicExtensionConfig for Bn254Fp6Config {
+    type BaseElement = Fp2Element;
+    type BaseField = FP2_BN254;
+
+    /// ξ = 9 + u
+    fn non_residue() -> Fp2Element {
+        QuadraticElement::new(U256::from(9), U256::one())
+    }
+
+    /// γ = ξ^((p − 1) / 3)
+    fn frobenius_coefficient() -> Fp2Element {
+        QuadraticElement::new(FROBENIUS_FP6_C0, FROBENIUS_FP6_C1)
+    }
+}
+
+#[derive(Debug)]
+pub struct Bn254Fp12Config;
+
+impl QuadraticExtensionConfig for Bn254Fp12Config {
+    type BaseElement = Fp6Element;
+    type BaseField = FP6_BN254;
+
+    /// β = v
+    fn non_residue() -> Fp6Element {
+        let zero = QuadraticElement::new(U256::zero(), U256::zero());
+        let one = QuadraticElement::new(U256::one(), U256::zero());
+        CubicElement::new(zero.clone(), one, zero)
+    }
+
+    /// γ = v^((p − 1) / 2) = ξ^((p − 1) / 6), which lies in 𝔽ₚ²
+    fn frobenius_coefficient() -> Fp6Element {
+        let zero = QuadraticElement::new(U256::zero(), U256::zero());
+        CubicElement::new(
+            QuadraticElement::new(FROBENIUS_FP12_C0, FROBENIUS_FP12_C1),
+            zero.clone(),
+            zero,
+        )
+    }
+
+    /// (c0 + c1·v + c2·v²)·v = ξ·c2 + c0·v + c1·v²
+    fn mul_by_non_residue(a: &Fp6Element) -> Fp6Element {
+        CubicElement::new(
+            Bn254Fp6Config::mul_by_non_residue(&a.c2),
+            a.c0.clone(),
+            a.c1.clone(),
+        )
+    }
+}
+
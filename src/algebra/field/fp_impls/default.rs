@@ -8,4 +8,4 @@ impl Finite for U256 {
     fn order(&self) -> U256 {
         U256::MAX
     }
-}
\ No newline at end of file
+}
@@ -1,166 +1,80 @@
 #![allow(non_camel_case_types)]
 
-// -----------------------------------------------------------------------------
-// External and internal module imports
-// -----------------------------------------------------------------------------
-use modular_math::mod_math::ModMath;
-use primitive_types::U256;
+use primitive_types::{U256, U512};
+
+use crate::{
+    algebra::field::fp::{FieldModulus, Fp},
+    helpers::constant_time::{Choice, ConditionallySelectable},
+};
 
+/// The secp256k1 base field prime `p = 2²⁵⁶ − 2³² − 977`.
 pub const P: U256 = U256([
-    0xFFFFFC2F,
-    0xFFFFFFFEFFFFFFFF,
+    0xFFFFFFFEFFFFFC2F,
+    0xFFFFFFFFFFFFFFFF,
     0xFFFFFFFFFFFFFFFF,
     0xFFFFFFFFFFFFFFFF,
 ]);
 
-use crate::{
-    algebra::{
-        field::{Field, FiniteField},
-        group::{element::GroupOps, Group},
-        operations::{Additive, BinaryOperation, Multiplicative},
-        properties::{
-            Associative, Commutative, Distributive, Finite, Identity, Invertible,
-        },
-        AlgebraicStructure,
-    },
-    helpers::mod_inverse,
-};
+/// `2²⁵⁶ mod p = 2³² + 977`, the constant used to fold the high half of a product.
+const C: u64 = 0x1000003D1;
 
 // -----------------------------------------------------------------------------
-// Definition of the finite field FP_SECP265K1 (Field of integers modulo 7)
+// Definition of the finite field FP_SECP265K1 (Field of integers modulo P)
 // -----------------------------------------------------------------------------
 #[derive(Debug)]
-pub struct FP_SECP265K1 {
-    // The underlying value stored as a U256
-    pub value: U256,
-}
-
-// -----------------------------------------------------------------------------
-// Implement AlgebraicStructure for FP_SECP265K1 (both for additive and multiplicative operations)
-// -----------------------------------------------------------------------------
-impl AlgebraicStructure<Additive> for FP_SECP265K1 {
-    // Both operations use U256 as the element type
-    type Element = U256;
-}
-
-impl AlgebraicStructure<Multiplicative> for FP_SECP265K1 {
-    type Element = U256;
-}
-
-// -----------------------------------------------------------------------------
-// Implement BinaryOperation for addition on FP_SECP265K1 elements
-// (Computes (a + b) mod 7)
-// -----------------------------------------------------------------------------
-impl BinaryOperation<Additive, FP_SECP265K1> for U256 {
-    fn op(
-        a: &<FP_SECP265K1 as AlgebraicStructure<Additive>>::Element,
-        b: &<FP_SECP265K1 as AlgebraicStructure<Additive>>::Element,
-    ) -> <FP_SECP265K1 as AlgebraicStructure<Additive>>::Element {
-        (a + b) % P
-    }
-}
-
-// -----------------------------------------------------------------------------
-// Implement BinaryOperation for multiplication on FP_SECP265K1 elements
-// (Computes (a * b) mod 7)
-// -----------------------------------------------------------------------------
-impl BinaryOperation<Multiplicative, FP_SECP265K1> for U256 {
-    fn op(
-        a: &<FP_SECP265K1 as AlgebraicStructure<Multiplicative>>::Element,
-        b: &<FP_SECP265K1 as AlgebraicStructure<Multiplicative>>::Element,
-    ) -> <FP_SECP265K1 as AlgebraicStructure<Multiplicative>>::Element {
-        ((a % P) * (b % P)) % P
-    }
-}
+pub struct Secp256k1Modulus;
 
-// -----------------------------------------------------------------------------
-// Constructor and helper methods for FP_SECP265K1
-// -----------------------------------------------------------------------------
-impl FP_SECP265K1 {
-    /// Creates a new FP_SECP265K1 element by applying modulo reduction to the given value.
-    pub fn new(value: U256) -> Self {
-        Self {
-            // Use ModMath utility to ensure the value is reduced modulo 7
-            value: ModMath::new(P).modulus(value),
-        }
-    }
-}
+impl FieldModulus for Secp256k1Modulus {
+    const MODULUS: U256 = P;
 
-// -----------------------------------------------------------------------------
-// Implement the Finite trait for FP_SECP265K1 and for U256 (for compatibility)
-// -----------------------------------------------------------------------------
-impl Finite for FP_SECP265K1 {
-    /// Returns the order of the finite field FP_SECP265K1, which is 7.
-    fn order(&self) -> U256 {
-        P
+    /// Multiplies through a widening product followed by the
+    /// pseudo-Mersenne reduction in [`reduce_wide`].
+    fn mul(a: &U256, b: &U256) -> U256 {
+        reduce_wide(&a.full_mul(*b))
     }
 }
 
-// -----------------------------------------------------------------------------
-// Field and FiniteField implementations for FP_SECP265K1
-// -----------------------------------------------------------------------------
-impl Field<U256> for FP_SECP265K1 {}
-impl FiniteField<U256> for FP_SECP265K1 {}
+pub type FP_SECP265K1 = Fp<Secp256k1Modulus>;
 
 // -----------------------------------------------------------------------------
-// Distributive, Commutative, Group, and Associative trait implementations
-// These implementations are marked for both FP_SECP265K1 and U256 as needed.
+// Pseudo-Mersenne reduction
 // -----------------------------------------------------------------------------
-impl Distributive<FP_SECP265K1, U256> for FP_SECP265K1 {}
-impl Distributive<FP_SECP265K1, U256> for U256 {}
-
-impl Commutative<Additive, FP_SECP265K1> for U256 {}
-impl Commutative<Multiplicative, FP_SECP265K1> for U256 {}
-
-impl Group<Additive> for FP_SECP265K1 {}
-impl Group<Multiplicative> for FP_SECP265K1 {}
 
-impl Associative<Additive, FP_SECP265K1> for U256 {}
-impl Associative<Multiplicative, FP_SECP265K1> for U256 {}
+/// Reduces a 512-bit value modulo `p = 2²⁵⁶ − C`.
+///
+/// Writing `x = H·2²⁵⁶ + L`, we have `x ≡ L + H·C (mod p)`.
+/// Folding the high half twice brings the value below `2²⁵⁶`,
+/// after which at most one subtraction of `p` is needed.
+pub fn reduce_wide(x: &U512) -> U256 {
+    let (lo, hi) = (&x.0[..4], &x.0[4..]);
 
-impl GroupOps<Additive, FP_SECP265K1> for U256 {}
-impl GroupOps<Multiplicative, FP_SECP265K1> for U256 {}
-
-// -----------------------------------------------------------------------------
-// Implement Invertible trait for finding multiplicative and additive inverses.
-// -----------------------------------------------------------------------------
-impl Invertible<Multiplicative, FP_SECP265K1> for U256 {
-    /// Computes the multiplicative inverse of an element a in FP_SECP265K1.
-    /// Returns None if a is zero.
-    fn inverse(
-        a: &<FP_SECP265K1 as AlgebraicStructure<Multiplicative>>::Element,
-    ) -> Option<<FP_SECP265K1 as AlgebraicStructure<Multiplicative>>::Element> {
-        if a.eq(&U256::zero()) {
-            return None;
-        }
-        // Use the mod_inverse helper to compute the inverse modulo 7
-        mod_inverse(*a, P)
+    // First fold: L + H·C fits into 4 limbs plus a top word below 2³⁴.
+    let mut acc = [0u64; 4];
+    let mut carry: u128 = 0;
+    for i in 0..4 {
+        let t = lo[i] as u128 + hi[i] as u128 * C as u128 + carry;
+        acc[i] = t as u64;
+        carry = t >> 64;
     }
-}
 
-impl Invertible<Additive, FP_SECP265K1> for U256 {
-    /// Computes the additive inverse of an element a in FP_SECP265K1.
-    /// Conventionally, the additive inverse is defined as (7 - a) mod 7.
-    fn inverse(
-        a: &<FP_SECP265K1 as AlgebraicStructure<Additive>>::Element, // Prefer using AlgebraicStructure<Additive>
-    ) -> Option<<FP_SECP265K1 as AlgebraicStructure<Additive>>::Element> {
-        Some((P - a) % P)
+    // Second fold: the top word times C is below 2⁶⁷.
+    let top = carry * C as u128;
+    let mut carry: u128 = 0;
+    for (i, limb) in acc.iter_mut().enumerate() {
+        let addend = match i {
+            0 => top as u64 as u128,
+            1 => top >> 64,
+            _ => 0,
+        };
+        let t = *limb as u128 + addend + carry;
+        *limb = t as u64;
+        carry = t >> 64;
     }
-}
 
-// -----------------------------------------------------------------------------
-// Implement Identity trait to provide the neutral elements.
-// -----------------------------------------------------------------------------
-impl Identity<Multiplicative, FP_SECP265K1> for FP_SECP265K1 {
-    /// Returns the multiplicative identity (1) in FP_SECP265K1.
-    fn identity() -> U256 {
-        U256::one()
-    }
-}
-
-impl Identity<Additive, FP_SECP265K1> for FP_SECP265K1 {
-    /// Returns the additive identity (0) in FP_SECP265K1.
-    fn identity() -> U256 {
-        U256::zero()
-    }
+    // A final carry out of 2²⁵⁶ leaves a small value behind; fold it once more.
+    // Both corrections are applied through masks, so the reduction has no
+    // data-dependent branches and is safe to use on secret operands.
+    let result = U256(acc).overflowing_add(U256::from(C * carry as u64)).0;
+    let (reduced, borrow) = result.overflowing_sub(P);
+    U256::conditional_select(&reduced, &result, Choice::from_bit(borrow as u64))
 }
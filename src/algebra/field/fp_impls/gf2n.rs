@@ -0,0 +1,32 @@
+#![allow(non_camel_case_types)]
+
+use crate::algebra::field::binary::{BinaryField, BinaryFieldConfig};
+
+// -----------------------------------------------------------------------------
+// GF(2⁸) with the AES / Reed–Solomon polynomial x⁸ + x⁴ + x³ + x + 1
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub struct Gf2_8Config;
+
+impl BinaryFieldConfig for Gf2_8Config {
+    const DEGREE: u32 = 8;
+    const REDUCTION: u128 = 0x1B;
+}
+
+pub type GF_2_8 = BinaryField<Gf2_8Config>;
+
+// -----------------------------------------------------------------------------
+// GF(2¹²⁸) with the GHASH polynomial x¹²⁸ + x⁷ + x² + x + 1
+//
+// Elements use the plain polynomial basis (bit i is the coefficient of xⁱ);
+// GCM's bit-reflected block encoding has to be converted by the caller.
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub struct Gf2_128Config;
+
+impl BinaryFieldConfig for Gf2_128Config {
+    const DEGREE: u32 = 128;
+    const REDUCTION: u128 = 0x87;
+}
+
+pub type GF_2_128 = BinaryField<Gf2_128Config>;
@@ -0,0 +1,26 @@
+#![allow(non_camel_case_types)]
+
+use primitive_types::U256;
+
+use crate::algebra::field::{fp::FieldModulus, TwoAdicField};
+
+// -----------------------------------------------------------------------------
+// Definition of the Goldilocks field 𝔽ₚ with p = 2⁶⁴ − 2³² + 1
+// (p − 1 = 2³²·(2³² − 1), so it supports NTTs of length up to 2³²)
+// -----------------------------------------------------------------------------
+crate::prime_field!(pub FP_GOLDILOCKS, GoldilocksModulus, U256([0xFFFFFFFF00000001, 0, 0, 0]));
+
+impl TwoAdicField<U256> for FP_GOLDILOCKS {
+    const TWO_ADICITY: u32 = 32;
+
+    /// 7 generates the multiplicative group of the Goldilocks field.
+    fn multiplicative_generator() -> U256 {
+        U256::from(7)
+    }
+
+    /// Returns `7^((p − 1) / 2³²)`.
+    fn two_adic_generator() -> U256 {
+        let exp = (GoldilocksModulus::MODULUS - 1) >> Self::TWO_ADICITY as usize;
+        FP_GOLDILOCKS::pow(&Self::multiplicative_generator(), &exp)
+    }
+}
@@ -1,3 +1,6 @@
 pub mod fp7_impl;
 pub mod fp_secp256k1;
-pub mod default;
\ No newline at end of file
+pub mod default;
+pub mod bn254;
+pub mod gf2n;
+pub mod goldilocks;
\ No newline at end of file
@@ -6,6 +6,15 @@ use super::{
 };
 
 pub mod operation_helpers;
+pub mod binary;
+pub mod prime_power;
+pub mod element;
+pub mod extension;
+pub mod fp;
+pub mod montgomery;
+pub mod ntt;
+pub mod safegcd;
+pub mod sqrt;
 pub mod fp_impls;
 
 /// A trait representing a mathematical **field** over elements of type `T`.
@@ -37,7 +46,8 @@ where
 ///
 /// This is a field with a finite number of elements. The structure `Self`
 /// must implement both field behavior and provide a way to determine its order
-/// (i.e. total number of distinct elements).
+/// (i.e. total number of distinct elements). The order belongs to the field,
+/// so nothing beyond the field operations is required of the element type `T`.
 pub trait FiniteField<T>:
     Field<T>
     + Finite
@@ -49,6 +59,46 @@ where
         + GroupOps<Multiplicative, Self>
         + Commutative<Additive, Self>
         + Commutative<Multiplicative, Self>
-        + Distributive<Self, T>
-        + Finite,
+        + Distributive<Self, T>,
 {}
+
+/// A trait for fields whose multiplicative group has a large **2-power subgroup**,
+/// i.e. `2ˢ | (q − 1)` for some `s = TWO_ADICITY`.
+///
+/// Such fields contain primitive `2ᵏ`-th roots of unity for every `k ≤ s`,
+/// which is what radix-2 number-theoretic transforms are evaluated over.
+pub trait TwoAdicField<T>:
+    Field<T>
+where
+    T: PartialEq
+        + BinaryOperation<Additive, Self>
+        + BinaryOperation<Multiplicative, Self>
+        + GroupOps<Additive, Self>
+        + GroupOps<Multiplicative, Self>
+        + Commutative<Additive, Self>
+        + Commutative<Multiplicative, Self>
+        + Distributive<Self, T>,
+{
+    /// The largest `s` such that `2ˢ` divides the order of the multiplicative group.
+    const TWO_ADICITY: u32;
+
+    /// Returns a generator of the whole multiplicative group,
+    /// commonly used as the shift of a coset.
+    fn multiplicative_generator() -> T;
+
+    /// Returns a primitive `2ˢ`-th root of unity, with `s = TWO_ADICITY`.
+    fn two_adic_generator() -> T;
+
+    /// Returns a primitive `2ᵏ`-th root of unity,
+    /// or None if `k` exceeds `TWO_ADICITY`.
+    fn root_of_unity(k: u32) -> Option<T> {
+        if k > Self::TWO_ADICITY {
+            return None;
+        }
+        let mut root = Self::two_adic_generator();
+        for _ in k..Self::TWO_ADICITY {
+            root = <T as BinaryOperation<Multiplicative, Self>>::op(&root, &root);
+        }
+        Some(root)
+    }
+}
@@ -0,0 +1,132 @@
+use primitive_types::U256;
+
+use crate::helpers::constant_time::{Choice, ConditionallySelectable};
+
+// -----------------------------------------------------------------------------
+// Montgomery arithmetic over 4 × 64-bit limbs
+//
+// An element `a` of 𝔽ₚ is represented in Montgomery form as `a·R mod p`,
+// with `R = 2²⁵⁶`. Multiplying two such values with `montgomery_mul`
+// yields `a·b·R mod p` using only word multiplications and shifts,
+// avoiding the division hidden behind `%`.
+// -----------------------------------------------------------------------------
+
+/// Computes `2²⁵⁶ mod p`, i.e. the Montgomery form of `1`.
+pub const fn r(p: &[u64; 4]) -> [u64; 4] {
+    pow2_mod(256, p)
+}
+
+/// Computes `2⁵¹² mod p`, used to convert values into Montgomery form.
+pub const fn r2(p: &[u64; 4]) -> [u64; 4] {
+    pow2_mod(512, p)
+}
+
+/// Computes `-p⁻¹ mod 2⁶⁴` from the lowest limb of an odd modulus.
+pub const fn neg_inv(p0: u64) -> u64 {
+    // p0^(2⁶³ - 1) = p0⁻¹ mod 2⁶⁴, since the unit group mod 2⁶⁴ has exponent 2⁶².
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 63 {
+        inv = inv.wrapping_mul(inv);
+        inv = inv.wrapping_mul(p0);
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// Computes `2ᵉ mod p` by repeated modular doubling of `1`.
+const fn pow2_mod(e: usize, p: &[u64; 4]) -> [u64; 4] {
+    let mut x = [1u64, 0, 0, 0];
+    let mut i = 0;
+    while i < e {
+        // x = 2x, remembering the bit shifted out of the top limb
+        let carry = x[3] >> 63;
+        x[3] = (x[3] << 1) | (x[2] >> 63);
+        x[2] = (x[2] << 1) | (x[1] >> 63);
+        x[1] = (x[1] << 1) | (x[0] >> 63);
+        x[0] <<= 1;
+        if carry != 0 || !lt(&x, p) {
+            x = sub(&x, p);
+        }
+        i += 1;
+    }
+    x
+}
+
+/// Returns `true` if `a < b`.
+const fn lt(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+/// Computes `a - b` modulo `2²⁵⁶`.
+const fn sub(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut out = [0u64; 4];
+    let mut borrow = 0u64;
+    let mut i = 0;
+    while i < 4 {
+        let (d1, b1) = a[i].overflowing_sub(b[i]);
+        let (d2, b2) = d1.overflowing_sub(borrow);
+        out[i] = d2;
+        borrow = (b1 | b2) as u64;
+        i += 1;
+    }
+    out
+}
+
+/// Computes `a + b·c + carry`, returning the low and high words.
+#[inline(always)]
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) * (c as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Computes `a + b + carry`, returning the low and high words.
+#[inline(always)]
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Montgomery multiplication (CIOS): computes `a·b·R⁻¹ mod p`.
+///
+/// `p` must be odd, `inv` must equal `-p⁻¹ mod 2⁶⁴`,
+/// and both operands must be smaller than `p`.
+pub fn montgomery_mul(a: &U256, b: &U256, p: &U256, inv: u64) -> U256 {
+    let (a, b, p) = (&a.0, &b.0, &p.0);
+    let mut t = [0u64; 6];
+
+    for &b_i in b {
+        // t += a · b_i
+        let mut carry = 0;
+        for j in 0..4 {
+            (t[j], carry) = mac(t[j], a[j], b_i, carry);
+        }
+        (t[4], t[5]) = adc(t[4], carry, 0);
+
+        // t = (t + m·p) / 2⁶⁴, where m is chosen so the lowest word vanishes
+        let m = t[0].wrapping_mul(inv);
+        let (_, mut carry) = mac(t[0], m, p[0], 0);
+        for j in 1..4 {
+            (t[j - 1], carry) = mac(t[j], m, p[j], carry);
+        }
+        (t[3], carry) = adc(t[4], carry, 0);
+        t[4] = t[5] + carry;
+        t[5] = 0;
+    }
+
+    // The intermediate result is below 2p; a single conditional subtraction
+    // suffices. It is applied through a mask, so that the multiplication has
+    // no data-dependent branches.
+    let result = U256([t[0], t[1], t[2], t[3]]);
+    let (reduced, borrow) = result.overflowing_sub(U256(*p));
+    // Keep the reduced value if the result overflowed 256 bits or did not underflow.
+    let use_reduced = Choice::from_bit(t[4]) | !Choice::from_bit(borrow as u64);
+    U256::conditional_select(&result, &reduced, use_reduced)
+}
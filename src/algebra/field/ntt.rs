@@ -0,0 +1,221 @@
+use primitive_types::U256;
+
+use crate::algebra::{
+    group::{element::GroupOps, extension::Power},
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{Commutative, Distributive, Identity, Invertible},
+};
+
+use super::{
+    operation_helpers::{add, mul, sub},
+    TwoAdicField,
+};
+
+// -----------------------------------------------------------------------------
+// Number-theoretic transform (NTT)
+//
+// The NTT of `a₀, …, aₙ₋₁` is the vector of evaluations `A(ωⁱ)` of the
+// polynomial `A(x) = Σ aⱼ·xʲ` at the powers of a primitive n-th root of
+// unity ω. All transforms work in place on slices whose length is a power
+// of two not exceeding `2^TWO_ADICITY`, using the iterative radix-2
+// Cooley–Tukey algorithm in `O(n log n)` field operations.
+// -----------------------------------------------------------------------------
+
+/// Computes the forward NTT of `values` in place:
+/// afterwards `values[i]` holds `A(ωⁱ)`.
+///
+/// # Panics
+///
+/// Panics if the length is not a power of two or exceeds `2^TWO_ADICITY`.
+pub fn ntt<F: TwoAdicField<T>, T>(values: &mut [T])
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    let root = root_for_length::<F, T>(values.len());
+    transform::<F, T>(values, &root);
+}
+
+/// Computes the inverse NTT of `values` in place,
+/// recovering the coefficients from the evaluations at the powers of ω.
+///
+/// # Panics
+///
+/// Panics if the length is not a power of two or exceeds `2^TWO_ADICITY`.
+pub fn intt<F: TwoAdicField<T>, T>(values: &mut [T])
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    let root = root_for_length::<F, T>(values.len());
+    let root_inv = <T as Invertible<Multiplicative, F>>::inverse(&root)
+        .expect("a root of unity is nonzero");
+    transform::<F, T>(values, &root_inv);
+
+    // Scale by n⁻¹, where n is the image of the length in the field
+    let n = <F as Power<Additive, F>>::pow(
+        &<F as Identity<Multiplicative, F>>::identity(),
+        U256::from(values.len()),
+    );
+    let n_inv = <T as Invertible<Multiplicative, F>>::inverse(&n)
+        .expect("the transform length is invertible in a two-adic field");
+    for value in values.iter_mut() {
+        *value = mul::<F, T>(value, &n_inv);
+    }
+}
+
+/// Computes the NTT over the coset `shift·⟨ω⟩` in place:
+/// afterwards `values[i]` holds `A(shift·ωⁱ)`.
+///
+/// Evaluating on a coset avoids the roots of unity themselves,
+/// e.g. to divide by the vanishing polynomial `xⁿ − 1` of the subgroup.
+///
+/// # Panics
+///
+/// Panics if the length is not a power of two or exceeds `2^TWO_ADICITY`.
+pub fn coset_ntt<F: TwoAdicField<T>, T>(values: &mut [T], shift: &T)
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    // A(shift·x) has coefficients aⱼ·shiftʲ
+    scale_by_powers::<F, T>(values, shift);
+    ntt::<F, T>(values);
+}
+
+/// Inverts [`coset_ntt`] in place, recovering the coefficients
+/// from the evaluations at `shift·ωⁱ`.
+///
+/// # Panics
+///
+/// Panics if the length is not a power of two or exceeds `2^TWO_ADICITY`,
+/// or if `shift` is zero.
+pub fn coset_intt<F: TwoAdicField<T>, T>(values: &mut [T], shift: &T)
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    intt::<F, T>(values);
+    let shift_inv = <T as Invertible<Multiplicative, F>>::inverse(shift)
+        .expect("the coset shift must be nonzero");
+    scale_by_powers::<F, T>(values, &shift_inv);
+}
+
+/// Returns a primitive root of unity whose order is the (power-of-two) length `n`.
+fn root_for_length<F: TwoAdicField<T>, T>(n: usize) -> T
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    assert!(n.is_power_of_two(), "NTT length must be a power of two");
+    F::root_of_unity(n.trailing_zeros()).expect("NTT length exceeds the two-adicity of the field")
+}
+
+/// Multiplies `values[j]` by `factorʲ`.
+fn scale_by_powers<F: TwoAdicField<T>, T>(values: &mut [T], factor: &T)
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    let mut power = <F as Identity<Multiplicative, F>>::identity();
+    for value in values.iter_mut() {
+        *value = mul::<F, T>(value, &power);
+        power = mul::<F, T>(&power, factor);
+    }
+}
+
+/// Iterative radix-2 Cooley–Tukey transform with respect to the
+/// primitive n-th root of unity `root`, where `n = values.len()`.
+fn transform<F: TwoAdicField<T>, T>(values: &mut [T], root: &T)
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    bit_reverse_permute(values);
+
+    // stage_roots[k] is a primitive 2ᵏ⁺¹-th root of unity
+    let log_n = n.trailing_zeros() as usize;
+    let mut stage_roots = vec![root.clone(); log_n];
+    for k in (0..log_n - 1).rev() {
+        stage_roots[k] = mul::<F, T>(&stage_roots[k + 1], &stage_roots[k + 1]);
+    }
+
+    for (stage, stage_root) in stage_roots.iter().enumerate() {
+        let half = 1 << stage;
+
+        let mut twiddles = Vec::with_capacity(half);
+        let mut w = <F as Identity<Multiplicative, F>>::identity();
+        for _ in 0..half {
+            twiddles.push(w.clone());
+            w = mul::<F, T>(&w, stage_root);
+        }
+
+        // Butterflies: (u, v) ↦ (u + w·v, u − w·v)
+        for chunk in values.chunks_mut(2 * half) {
+            let (low, high) = chunk.split_at_mut(half);
+            for ((u, v), w) in low.iter_mut().zip(high.iter_mut()).zip(&twiddles) {
+                let t = mul::<F, T>(v, w);
+                *v = sub::<F, T>(u, &t);
+                *u = add::<F, T>(u, &t);
+            }
+        }
+    }
+}
+
+/// Reorders `values` so that index `i` moves to the bit reversal of `i`.
+fn bit_reverse_permute<T>(values: &mut [T]) {
+    let n = values.len();
+    let shift = usize::BITS - n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> shift;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::algebra::{group::element::GroupOps, operations::{Additive, BinaryOperation, Multiplicative}, properties::{Commutative, Distributive, Invertible}};
+use crate::algebra::{group::element::GroupOps, operations::{Additive, BinaryOperation, Multiplicative}, properties::{Commutative, Distributive, Identity, Invertible}};
 
 use super::Field;
 
@@ -19,7 +19,7 @@ where
 }
 
 #[inline]
-fn add<F: Field<T>, T>(a: &T, b: &T) -> T
+pub fn add<F: Field<T>, T>(a: &T, b: &T) -> T
 where
     T: Clone
         + GroupOps<Additive, F>
@@ -66,4 +66,72 @@ where
         + Distributive<F, T>
 {
     add::<F, T>(a, a)
-}
\ No newline at end of file
+}
+
+/// Inverts every element of `elements` with a single field inversion
+/// (Montgomery's trick).
+///
+/// The nonzero elements are multiplied into running prefix products, the
+/// final product is inverted once, and the individual inverses are peeled
+/// off while walking backwards. This costs one call to
+/// `Invertible<Multiplicative, F>::inverse` plus `3n − 2` multiplications.
+///
+/// Zero has no inverse: its position in the output is `None`,
+/// and it is skipped in the products so the other entries are unaffected.
+/// Zeros are recognized after reduction, so non-canonical representatives
+/// of zero are reported as well.
+pub fn batch_inverse<F: Field<T>, T>(elements: &[T]) -> Vec<Option<T>>
+where
+    T: Clone
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>
+{
+    let zero = <F as Identity<Additive, F>>::identity();
+    let one = <F as Identity<Multiplicative, F>>::identity();
+
+    // prefix[i] holds the product of all nonzero elements before index i
+    // (None while that product is still the empty product).
+    let mut prefix: Vec<Option<T>> = Vec::with_capacity(elements.len());
+    let mut nonzero = Vec::with_capacity(elements.len());
+    let mut acc: Option<T> = None;
+    for element in elements {
+        prefix.push(acc.clone());
+        // The running product is nonzero, so it only becomes zero if the
+        // element is; multiplying the first element by one reduces it too
+        let product = mul::<F, T>(acc.as_ref().unwrap_or(&one), element);
+        let is_nonzero = product != zero;
+        if is_nonzero {
+            acc = Some(product);
+        }
+        nonzero.push(is_nonzero);
+    }
+
+    let mut result = vec![None; elements.len()];
+    let Some(product) = acc else {
+        // Every entry was zero.
+        return result;
+    };
+
+    // inv = (product of nonzero elements up to and including index i)⁻¹
+    let mut inv = <T as Invertible<Multiplicative, F>>::inverse(&product)
+        .expect("a product of nonzero field elements is invertible");
+    for (i, element) in elements.iter().enumerate().rev() {
+        if !nonzero[i] {
+            continue;
+        }
+        match &prefix[i] {
+            Some(before) => {
+                result[i] = Some(mul::<F, T>(&inv, before));
+                inv = mul::<F, T>(&inv, element);
+            }
+            // The first nonzero element: what is left is exactly its inverse.
+            None => result[i] = Some(inv.clone()),
+        }
+    }
+    result
+}
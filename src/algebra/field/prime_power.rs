@@ -0,0 +1,260 @@
+// -----------------------------------------------------------------------------
+// External and internal module imports
+// -----------------------------------------------------------------------------
+use std::marker::PhantomData;
+
+use primitive_types::U256;
+
+use crate::{
+    algebra::{
+        field::{
+            extension::Frobenius,
+            fp::{FieldModulus, Fp},
+            Field, FiniteField,
+        },
+        group::{element::GroupOps, extension::Power, Group},
+        operations::{Additive, BinaryOperation, Multiplicative},
+        properties::{
+            Associative, Commutative, Distributive, Finite, Identity, Invertible,
+        },
+        polynomial::Polynomial,
+        AlgebraicStructure,
+    },
+    helpers::{add_mod, sub_mod},
+};
+
+/// Parameters of a **finite field of prime-power order** 𝔽ₚₖ = 𝔽ₚ[x] / (f(x)),
+/// where `f` is a monic irreducible polynomial of degree `K` over 𝔽ₚ.
+///
+/// Elements are polynomials of degree below `K`, stored as `[U256; K]`
+/// coefficient arrays in polynomial basis: entry `i` is the coefficient of `xⁱ`.
+///
+/// Irreducibility of `f` is not checked; a reducible polynomial yields
+/// a ring with zero divisors rather than a field.
+pub trait PrimePowerFieldConfig<const K: usize>: std::fmt::Debug {
+    /// The modulus of the prime field 𝔽ₚ the coefficients live in.
+    type Modulus: FieldModulus;
+
+    /// `xᴷ mod f(x)`, i.e. the coefficients of `xᴷ − f(x)`, lowest degree first.
+    /// This is what replaces an overflowing `xᴷ` during multiplication.
+    const REDUCTION: [U256; K];
+}
+
+// -----------------------------------------------------------------------------
+// Definition of the generic prime-power field PrimePowerField<C, K>
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub struct PrimePowerField<C, const K: usize> {
+    _config: PhantomData<C>,
+}
+
+// Written by hand, since the derive would require `C: Default`.
+impl<C, const K: usize> Default for PrimePowerField<C, K> {
+    fn default() -> Self {
+        Self { _config: PhantomData }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Coefficient arithmetic in 𝔽ₚ
+// -----------------------------------------------------------------------------
+impl<C: PrimePowerFieldConfig<K>, const K: usize> PrimePowerField<C, K> {
+    /// Builds an element from its coefficients, reducing each modulo `p`.
+    pub fn from_coefficients(coefficients: [U256; K]) -> [U256; K] {
+        coefficients.map(|c| c % C::Modulus::MODULUS)
+    }
+
+    /// Embeds an element of the prime field 𝔽ₚ as a constant polynomial.
+    pub fn from_base(a: &U256) -> [U256; K] {
+        let mut result = [U256::zero(); K];
+        result[0] = *a % C::Modulus::MODULUS;
+        result
+    }
+
+    /// Returns the defining polynomial `f(x) = xᴷ − REDUCTION(x)`,
+    /// e.g. to check that it is irreducible.
+    pub fn modulus_polynomial() -> Polynomial<Fp<C::Modulus>> {
+        let mut coefficients: Vec<U256> = C::REDUCTION
+            .iter()
+            .map(|c| sub_mod(U256::zero(), *c, C::Modulus::MODULUS))
+            .collect();
+        coefficients.push(U256::one());
+        Polynomial::new(coefficients)
+    }
+
+    /// Multiplies every coefficient by an element of 𝔽ₚ.
+    pub fn mul_by_base(a: &[U256; K], scalar: &U256) -> [U256; K] {
+        a.map(|c| <U256 as BinaryOperation<Multiplicative, Fp<C::Modulus>>>::op(&c, scalar))
+    }
+
+    /// Returns the norm `N(a) = a · aᵖ · … · aᵖᴷ⁻¹`, an element of 𝔽ₚ.
+    pub fn norm(a: &[U256; K]) -> U256 {
+        <[U256; K] as BinaryOperation<Multiplicative, Self>>::op(a, &Self::conjugates_product(a))[0]
+    }
+
+    /// Returns `aᵖ · aᵖ² · … · aᵖᴷ⁻¹`, the product of all conjugates of `a` except `a` itself.
+    fn conjugates_product(a: &[U256; K]) -> [U256; K] {
+        let mut conjugate = *a;
+        let mut product = <Self as Identity<Multiplicative, Self>>::identity();
+        for _ in 1..K {
+            conjugate = <Self as Frobenius<[U256; K]>>::frobenius_map(&conjugate, 1);
+            product = <[U256; K] as BinaryOperation<Multiplicative, Self>>::op(&product, &conjugate);
+        }
+        product
+    }
+}
+
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Frobenius<[U256; K]> for PrimePowerField<C, K> {
+    const CHARACTERISTIC: U256 = C::Modulus::MODULUS;
+
+    /// Computes `a^(p^power)` by repeated exponentiation by `p`.
+    fn frobenius_map(a: &[U256; K], power: usize) -> [U256; K] {
+        let mut result = *a;
+        for _ in 0..power {
+            result = <Self as Power<Multiplicative, Self>>::pow(&result, C::Modulus::MODULUS);
+        }
+        result
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement AlgebraicStructure for PrimePowerField<C, K> (both for additive and multiplicative operations)
+// -----------------------------------------------------------------------------
+impl<C: PrimePowerFieldConfig<K>, const K: usize> AlgebraicStructure<Additive> for PrimePowerField<C, K> {
+    type Element = [U256; K];
+}
+
+impl<C: PrimePowerFieldConfig<K>, const K: usize> AlgebraicStructure<Multiplicative> for PrimePowerField<C, K> {
+    type Element = [U256; K];
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for addition (coefficient-wise modulo p)
+// -----------------------------------------------------------------------------
+impl<C: PrimePowerFieldConfig<K>, const K: usize> BinaryOperation<Additive, PrimePowerField<C, K>> for [U256; K] {
+    fn op(a: &[U256; K], b: &[U256; K]) -> [U256; K] {
+        let mut result = [U256::zero(); K];
+        for (i, c) in result.iter_mut().enumerate() {
+            *c = add_mod(a[i], b[i], C::Modulus::MODULUS);
+        }
+        result
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for multiplication
+// (Schoolbook product of degree 2K − 2, then folding xᴷ⁺ⁱ back with REDUCTION
+// from the top coefficient down)
+// -----------------------------------------------------------------------------
+impl<C: PrimePowerFieldConfig<K>, const K: usize> BinaryOperation<Multiplicative, PrimePowerField<C, K>>
+    for [U256; K]
+{
+    fn op(a: &[U256; K], b: &[U256; K]) -> [U256; K] {
+        let p = C::Modulus::MODULUS;
+        let mul = |x: &U256, y: &U256| <U256 as BinaryOperation<Multiplicative, Fp<C::Modulus>>>::op(x, y);
+
+        let mut product = vec![U256::zero(); 2 * K - 1];
+        for (i, a_i) in a.iter().enumerate() {
+            for (j, b_j) in b.iter().enumerate() {
+                product[i + j] = add_mod(product[i + j], mul(a_i, b_j), p);
+            }
+        }
+
+        for i in (K..product.len()).rev() {
+            let top = product[i];
+            for (j, r_j) in C::REDUCTION.iter().enumerate() {
+                product[i - K + j] = add_mod(product[i - K + j], mul(&top, r_j), p);
+            }
+        }
+
+        let mut result = [U256::zero(); K];
+        result.copy_from_slice(&product[..K]);
+        result
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement the Finite trait for PrimePowerField<C, K>
+// -----------------------------------------------------------------------------
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Finite for PrimePowerField<C, K> {
+    /// Returns the order of the field, which is pᴷ.
+    ///
+    /// # Panics
+    ///
+    /// Panics if pᴷ does not fit into a `U256`.
+    fn order(&self) -> U256 {
+        C::Modulus::MODULUS
+            .checked_pow(U256::from(K))
+            .expect("field order pᴷ does not fit into U256")
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Field and FiniteField implementations for PrimePowerField<C, K>
+// -----------------------------------------------------------------------------
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Field<[U256; K]> for PrimePowerField<C, K> {}
+impl<C: PrimePowerFieldConfig<K>, const K: usize> FiniteField<[U256; K]> for PrimePowerField<C, K> {}
+
+// -----------------------------------------------------------------------------
+// Distributive, Commutative, Group, and Associative trait implementations
+// -----------------------------------------------------------------------------
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Distributive<PrimePowerField<C, K>, [U256; K]> for PrimePowerField<C, K> {}
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Distributive<PrimePowerField<C, K>, [U256; K]> for [U256; K] {}
+
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Commutative<Additive, PrimePowerField<C, K>> for [U256; K] {}
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Commutative<Multiplicative, PrimePowerField<C, K>> for [U256; K] {}
+
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Group<Additive> for PrimePowerField<C, K> {}
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Group<Multiplicative> for PrimePowerField<C, K> {}
+
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Associative<Additive, PrimePowerField<C, K>> for [U256; K] {}
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Associative<Multiplicative, PrimePowerField<C, K>> for [U256; K] {}
+
+impl<C: PrimePowerFieldConfig<K>, const K: usize> GroupOps<Additive, PrimePowerField<C, K>> for [U256; K] {}
+impl<C: PrimePowerFieldConfig<K>, const K: usize> GroupOps<Multiplicative, PrimePowerField<C, K>> for [U256; K] {}
+
+// -----------------------------------------------------------------------------
+// Implement Invertible trait for finding multiplicative and additive inverses.
+// -----------------------------------------------------------------------------
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Invertible<Multiplicative, PrimePowerField<C, K>> for [U256; K] {
+    /// Computes `a⁻¹ = (aᵖ · … · aᵖᴷ⁻¹) / N(a)` (Itoh–Tsujii), so only the
+    /// norm has to be inverted, in 𝔽ₚ, and pᴷ never has to be formed.
+    /// Returns None if a is zero.
+    fn inverse(a: &[U256; K]) -> Option<[U256; K]> {
+        let norm_inv = <U256 as Invertible<Multiplicative, Fp<C::Modulus>>>::inverse(
+            &PrimePowerField::<C, K>::norm(a),
+        )?;
+        Some(PrimePowerField::<C, K>::mul_by_base(
+            &PrimePowerField::<C, K>::conjugates_product(a),
+            &norm_inv,
+        ))
+    }
+}
+
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Invertible<Additive, PrimePowerField<C, K>> for [U256; K] {
+    /// Negates every coefficient modulo p.
+    fn inverse(a: &[U256; K]) -> Option<[U256; K]> {
+        Some(a.map(|c| sub_mod(U256::zero(), c, C::Modulus::MODULUS)))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement Identity trait to provide the neutral elements.
+// -----------------------------------------------------------------------------
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Identity<Multiplicative, PrimePowerField<C, K>>
+    for PrimePowerField<C, K>
+{
+    /// Returns the multiplicative identity, the constant polynomial 1.
+    fn identity() -> [U256; K] {
+        let mut one = [U256::zero(); K];
+        one[0] = U256::one();
+        one
+    }
+}
+
+impl<C: PrimePowerFieldConfig<K>, const K: usize> Identity<Additive, PrimePowerField<C, K>> for PrimePowerField<C, K> {
+    /// Returns the additive identity, the zero polynomial.
+    fn identity() -> [U256; K] {
+        [U256::zero(); K]
+    }
+}
@@ -0,0 +1,107 @@
+use primitive_types::U256;
+
+use crate::helpers::constant_time::{
+    ct_add_mod, ct_half_mod, ct_neg_mod, Choice, ConditionallySelectable,
+};
+
+// -----------------------------------------------------------------------------
+// Constant-time modular inversion via Bernstein–Yang divsteps ("safegcd")
+//
+// Starting from (δ, f, g) = (1, p, a), every divstep either swaps and
+// subtracts, or adds, and then halves g. After a fixed number of steps
+// g = 0 and f = ±gcd(p, a) = ±1. Alongside f and g we track d, e with
+//     f ≡ d·a (mod p),  g ≡ e·a (mod p),
+// so at the end a⁻¹ ≡ ±d. The sequence of operations never depends on
+// the input, which keeps the inversion free of secret-dependent branches.
+// -----------------------------------------------------------------------------
+
+/// Number of divsteps sufficient for any modulus below 2²⁵⁶:
+/// ⌈(49·256 + 57) / 17⌉, the bound proven by Bernstein and Yang.
+const DIVSTEPS: usize = 742;
+
+/// A signed 320-bit integer in two's complement, wide enough for `f` and `g`.
+type Wide = [u64; 5];
+
+/// Computes `a⁻¹ mod p` in constant time for an odd prime `p` and `a < p`.
+///
+/// Returns `0` when `a = 0`; callers are expected to handle that case.
+pub fn inverse(a: &U256, p: &U256) -> U256 {
+    let mut delta: i64 = 1;
+    let mut f = widen(p);
+    let mut g = widen(a);
+    let mut d = U256::zero();
+    let mut e = U256::one();
+
+    for _ in 0..DIVSTEPS {
+        let g_odd = Choice::from_bit(g[0] & 1);
+        // δ > 0 exactly when -δ is negative.
+        let delta_positive = Choice::from_bit((delta.wrapping_neg() as u64) >> 63);
+        let swap = delta_positive & g_odd;
+
+        // (δ, f, g, d, e) ← (-δ, g, -f, e, -d) when swapping.
+        delta = i64::conditional_select(&delta, &delta.wrapping_neg(), swap);
+        let (new_f, new_g) = (select(&f, &g, swap), select(&g, &negate(&f), swap));
+        f = new_f;
+        g = new_g;
+        let (new_d, new_e) = (
+            U256::conditional_select(&d, &e, swap),
+            U256::conditional_select(&e, &ct_neg_mod(&d, p), swap),
+        );
+        d = new_d;
+        e = new_e;
+        delta += 1;
+
+        // f is always odd, so after a swap g is odd exactly when it was before.
+        g = select(&g, &add(&g, &f), g_odd);
+        e = U256::conditional_select(&e, &ct_add_mod(&e, &d, p), g_odd);
+
+        // g is now even: halve it, and e modulo p along with it.
+        g = shr1(&g);
+        e = ct_half_mod(&e, p);
+    }
+
+    // f = ±1 here; flip the sign of d if f is negative.
+    let f_negative = Choice::from_bit(f[4] >> 63);
+    U256::conditional_select(&d, &ct_neg_mod(&d, p), f_negative)
+}
+
+fn widen(x: &U256) -> Wide {
+    [x.0[0], x.0[1], x.0[2], x.0[3], 0]
+}
+
+fn select(a: &Wide, b: &Wide, choice: Choice) -> Wide {
+    let mut out = [0u64; 5];
+    for (i, limb) in out.iter_mut().enumerate() {
+        *limb = u64::conditional_select(&a[i], &b[i], choice);
+    }
+    out
+}
+
+fn add(a: &Wide, b: &Wide) -> Wide {
+    let mut out = [0u64; 5];
+    let mut carry = 0u64;
+    for (i, limb) in out.iter_mut().enumerate() {
+        let t = a[i] as u128 + b[i] as u128 + carry as u128;
+        *limb = t as u64;
+        carry = (t >> 64) as u64;
+    }
+    out
+}
+
+fn negate(a: &Wide) -> Wide {
+    let mut inverted = [0u64; 5];
+    for (i, limb) in inverted.iter_mut().enumerate() {
+        *limb = !a[i];
+    }
+    add(&inverted, &[1, 0, 0, 0, 0])
+}
+
+/// Arithmetic shift right by one bit, preserving the sign.
+fn shr1(a: &Wide) -> Wide {
+    let mut out = [0u64; 5];
+    for i in 0..4 {
+        out[i] = (a[i] >> 1) | (a[i + 1] << 63);
+    }
+    out[4] = ((a[4] as i64) >> 1) as u64;
+    out
+}
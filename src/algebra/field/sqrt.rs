@@ -0,0 +1,90 @@
+use primitive_types::U256;
+
+use super::fp::{FieldModulus, Fp};
+
+/// A trait for fields in which **square roots** can be computed.
+///
+/// An element `a` has a square root exactly when it is a quadratic residue,
+/// i.e. `a = r²` for some `r` in the field. In that case `-r` is a square
+/// root as well; implementations may return either of the two.
+pub trait Sqrt<T> {
+    /// Returns a square root of `a`, or `None` if `a` is not a quadratic residue.
+    fn sqrt(a: &T) -> Option<T>;
+}
+
+impl<M: FieldModulus> Sqrt<U256> for Fp<M> {
+    /// Computes a square root modulo the odd prime `p`.
+    ///
+    /// Uses the single exponentiation `a^((p+1)/4)` when `p ≡ 3 (mod 4)`
+    /// and falls back to Tonelli–Shanks otherwise.
+    fn sqrt(a: &U256) -> Option<U256> {
+        let p = M::MODULUS;
+        let a = *a % p;
+        if a.is_zero() {
+            return Some(a);
+        }
+
+        let root = if p.low_u64() & 3 == 3 {
+            // (p + 1) / 4, written so that p + 1 cannot overflow
+            Fp::<M>::pow(&a, &((p >> 2) + U256::one()))
+        } else {
+            tonelli_shanks::<M>(&a)?
+        };
+
+        // The candidate is only a root if a was a quadratic residue.
+        if M::mul(&root, &root) == a {
+            Some(root)
+        } else {
+            None
+        }
+    }
+}
+
+/// Tonelli–Shanks square root for a nonzero `a < p`.
+///
+/// Writes `p − 1 = q·2ˢ` with `q` odd and repeatedly corrects the candidate
+/// `a^((q+1)/2)` by powers of a 2ˢ-th root of unity built from a non-residue.
+fn tonelli_shanks<M: FieldModulus>(a: &U256) -> Option<U256> {
+    let p_minus_one = M::MODULUS - U256::one();
+    let one = U256::one();
+
+    if Fp::<M>::legendre(a) != 1 {
+        return None;
+    }
+
+    let s = p_minus_one.trailing_zeros() as usize;
+    let q = p_minus_one >> s;
+
+    // Find the smallest quadratic non-residue z.
+    let mut z = U256::from(2);
+    while Fp::<M>::legendre(&z) != -1 {
+        z += one;
+    }
+
+    let mut m = s;
+    let mut c = Fp::<M>::pow(&z, &q);
+    let mut t = Fp::<M>::pow(a, &q);
+    let mut r = Fp::<M>::pow(a, &((q + one) >> 1));
+
+    while t != one {
+        // Find the least i with t^(2ⁱ) = 1; it is always below m.
+        let mut i = 0;
+        let mut t_pow = t;
+        while t_pow != one {
+            t_pow = M::mul(&t_pow, &t_pow);
+            i += 1;
+        }
+
+        // b = c^(2^(m − i − 1))
+        let mut b = c;
+        for _ in 0..(m - i - 1) {
+            b = M::mul(&b, &b);
+        }
+
+        m = i;
+        c = M::mul(&b, &b);
+        t = M::mul(&t, &c);
+        r = M::mul(&r, &b);
+    }
+    Some(r)
+}
@@ -1,6 +1,12 @@
 use primitive_types::U256;
 
-use crate::algebra::{operations::BinaryOperationType, AlgebraicStructure};
+use crate::{
+    algebra::{
+        operations::{BinaryOperation, BinaryOperationType},
+        AlgebraicStructure,
+    },
+    helpers::constant_time::{Choice, ConditionallySelectable},
+};
 
 use super::{element::GroupOps, Group};
 
@@ -15,7 +21,59 @@ where
 {
     /// Raises the given `element` to the power of `exp`
     /// using the group operation defined by `O`.
+    ///
+    /// The number of group operations depends on the bits of `exp`,
+    /// so this must only be used with public exponents; see [`Power::pow_ct`].
     fn pow(element: &G::Element, exp: U256) -> G::Element;
+
+    /// Raises `element` to the power of `exp` with a Montgomery ladder.
+    ///
+    /// The ladder performs the same two group operations for each of the
+    /// 256 exponent bits and selects operands with conditional swaps, so the
+    /// sequence of group operations does not depend on a secret `exp`.
+    /// Every step uses `G::Element::op_ct`, so the result is only constant
+    /// time if that operation does not branch on its operands; the prime-field
+    /// multiplications and the elliptic-curve `add_ct` satisfy this, while for
+    /// example the modular additions do not.
+    fn pow_ct(element: &G::Element, exp: U256) -> G::Element
+    where
+        G::Element: ConditionallySelectable + Clone;
+}
+
+/// Blanket implementation of `Power` for every group, using
+/// square-and-multiply (or double-and-add for additive groups),
+/// which takes `O(log exp)` group operations.
+impl<O: BinaryOperationType, G: Group<O>> Power<O, G> for G
+where
+    <G as AlgebraicStructure<O>>::Element: GroupOps<O, G>,
+{
+    fn pow(element: &G::Element, exp: U256) -> G::Element {
+        let mut acc = G::identity();
+        for i in (0..exp.bits()).rev() {
+            acc = G::Element::op(&acc, &acc);
+            if exp.bit(i) {
+                acc = G::Element::op(&acc, element);
+            }
+        }
+        acc
+    }
+
+    fn pow_ct(element: &G::Element, exp: U256) -> G::Element
+    where
+        G::Element: ConditionallySelectable + Clone,
+    {
+        // Invariant: r1 = r0 · element
+        let mut r0 = G::identity();
+        let mut r1 = element.clone();
+        for i in (0..256).rev() {
+            let bit = Choice::from_bit(exp.bit(i) as u64);
+            G::Element::conditional_swap(&mut r0, &mut r1, bit);
+            r1 = G::Element::op_ct(&r0, &r1);
+            r0 = G::Element::op_ct(&r0, &r0);
+            G::Element::conditional_swap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
 }
 
 /// A trait representing **scalar multiplication**
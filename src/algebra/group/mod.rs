@@ -1,10 +1,11 @@
 use element::{ElementFinite, GroupOps};
+use extension::Power;
 use primitive_types::U256;
 
 use crate::helpers::{gcd, U256Range};
 
 use super::{
-    operations::{Additive, BinaryOperation, BinaryOperationType, Multiplicative},
+    operations::{Additive, BinaryOperationType, Multiplicative},
     properties::{Commutative, Finite, Identity},
     AlgebraicStructure,
 };
@@ -89,11 +90,7 @@ where
 
         for k in U256Range::new(U256::one(), order) {
             if gcd(k, order) == U256::one() {
-                let mut acc: Self::Element = Self::identity();
-                for _ in U256Range::new(U256::zero(), k) {
-                    acc = Self::Element::op(&acc, &generator);
-                }
-                result.push(acc);
+                result.push(<Self as Power<O, Self>>::pow(&generator, k));
             }
         }
         result
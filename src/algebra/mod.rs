@@ -1,5 +1,4 @@
 use operations::{BinaryOperation, BinaryOperationType};
-use primitive_types::U256;
 
 pub mod group;
 pub mod elliptic_curve;
@@ -9,6 +8,7 @@ pub mod operations;
 pub mod monoid;
 pub mod semigroup;
 pub mod ring;
+pub mod polynomial;
 
 /// A trait representing a generic **algebraic structure**
 /// parameterized by a binary operation `O`.
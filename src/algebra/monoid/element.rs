@@ -1,5 +1,5 @@
 use crate::algebra::{
-    monoid::Monoid, operations::{BinaryOperation, BinaryOperationType}, properties::Associative, semigroup::SemiGroupOps, AlgebraicStructure
+    monoid::Monoid, operations::BinaryOperationType, semigroup::SemiGroupOps
 };
 
 /// A trait defining **monoid-level operations** for elements of a monoid structure.
@@ -6,7 +6,8 @@ use crate::algebra::{
         Monoid
     }, operations::{
         Additive, 
-        BinaryOperation
+        BinaryOperation,
+        Multiplicative
     }, properties::{Associative, Identity}, semigroup::{SemiGroup, SemiGroupOps}, AlgebraicStructure
 };
 
@@ -35,4 +36,31 @@ impl Identity<Additive, U256> for U256 {
     fn identity() -> U256 {
         U256::zero()
     }
+}
+
+impl AlgebraicStructure<Multiplicative> for U256 {
+    type Element = U256;
+}
+
+impl Monoid<Multiplicative> for U256 {}
+impl SemiGroup<Multiplicative> for U256 {}
+impl SemiGroupOps<Multiplicative, U256> for U256 {}
+impl MonoidOps<Multiplicative, U256> for U256 {}
+
+impl Associative<Multiplicative, U256> for U256 {}
+
+// Multiplication wraps modulo 2²⁵⁶, so the product never panics on overflow
+impl BinaryOperation<Multiplicative, U256> for U256 {
+    fn op(
+        a: &<U256 as AlgebraicStructure<Multiplicative>>::Element, 
+        b: &<U256 as AlgebraicStructure<Multiplicative>>::Element
+    ) -> <U256 as AlgebraicStructure<Multiplicative>>::Element {
+        a.overflowing_mul(*b).0
+    }
+}
+
+impl Identity<Multiplicative, U256> for U256 {
+    fn identity() -> U256 {
+        U256::one()
+    }
 }
\ No newline at end of file
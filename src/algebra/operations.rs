@@ -3,12 +3,21 @@ use super::AlgebraicStructure;
 /// A trait that defines a **binary operation** over an algebraic structure `S`
 /// for a given operation type `O`.
 ///
-/// This trait provides a single method `op`, which combines two elements
-/// of the structure according to the semantics of `O` (e.g., addition or multiplication).
+/// The method `op` combines two elements of the structure according to
+/// the semantics of `O` (e.g., addition or multiplication); `op_ct` is its
+/// constant-time counterpart for operands that may be secret.
 pub trait BinaryOperation<O: BinaryOperationType, S: AlgebraicStructure<O>> {
     /// Applies the binary operation to the given operands `a` and `b`,
     /// returning the result.
     fn op(a: &S::Element, b: &S::Element) -> S::Element;
+
+    /// Applies the binary operation without branching on the values of `a` and `b`.
+    ///
+    /// Defaults to `op`, which is correct for operations that are already
+    /// branch-free; types with a faster variable-time `op` override this.
+    fn op_ct(a: &S::Element, b: &S::Element) -> S::Element {
+        Self::op(a, b)
+    }
 }
 
 /// Marker type representing the **additive operation** (e.g., `+`).
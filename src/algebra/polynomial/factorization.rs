@@ -0,0 +1,297 @@
+use primitive_types::U256;
+
+use crate::algebra::{
+    field::fp::{FieldModulus, Fp},
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::Invertible,
+};
+
+use super::Polynomial;
+
+// -----------------------------------------------------------------------------
+// Irreducibility testing and factorization over prime fields 𝔽ₚ
+//
+// Everything here rests on the fact that x^(pᵈ) − x is the product of all
+// monic irreducible polynomials over 𝔽ₚ whose degree divides d, so gcds with
+// it separate the factors of a polynomial by degree. Powers x^(pᵈ) are taken
+// modulo the polynomial being examined, by repeated exponentiation by p.
+// -----------------------------------------------------------------------------
+impl<M: FieldModulus> Polynomial<Fp<M>> {
+    /// Returns the polynomial `x`.
+    fn x() -> Self {
+        Self::monomial(U256::one(), 1)
+    }
+
+    /// Returns `self − other`.
+    fn minus(&self, other: &Self) -> Self {
+        <Self as BinaryOperation<Additive, Self>>::op(
+            self,
+            &<Self as Invertible<Additive, Self>>::inverse(other).expect("every polynomial has an additive inverse"),
+        )
+    }
+
+    /// Returns the quotient `self / divisor` of an exact division.
+    fn exact_div(&self, divisor: &Self) -> Self {
+        self.div_rem(divisor).expect("the divisor is nonzero").0
+    }
+
+    /// Applies `h ↦ hᵖ mod modulus` `times` times, i.e. computes `h^(pᵗⁱᵐᵉˢ) mod modulus`.
+    fn frobenius_mod(h: &Self, times: usize, modulus: &Self) -> Self {
+        let mut result = h.clone();
+        for _ in 0..times {
+            result = result.pow_mod(M::MODULUS, modulus).expect("the modulus is nonzero");
+        }
+        result
+    }
+
+    /// Tests whether the polynomial is irreducible over 𝔽ₚ (Rabin's test).
+    ///
+    /// A polynomial `f` of degree `n ≥ 1` is irreducible iff `f | x^(pⁿ) − x`
+    /// and `gcd(f, x^(p^(n/r)) − x) = 1` for every prime divisor `r` of `n`.
+    /// Constants, including zero, are not irreducible.
+    pub fn is_irreducible(&self) -> bool {
+        let n = match self.degree() {
+            Some(0) | None => return false,
+            Some(1) => return true,
+            Some(n) => n,
+        };
+        let f = self.monic();
+        let x = Self::x();
+
+        for r in prime_divisors(n) {
+            let h = Self::frobenius_mod(&x, n / r, &f).minus(&x);
+            if f.gcd(&h) != Self::one() {
+                return false;
+            }
+        }
+        Self::frobenius_mod(&x, n, &f).minus(&x).div_rem(&f).expect("f is nonzero").1.is_zero()
+    }
+
+    /// Splits a monic polynomial into square-free parts:
+    /// returns `(gᵢ, i)` pairs with `f = ∏ gᵢⁱ`, every `gᵢ` square-free,
+    /// monic, nonconstant and pairwise coprime.
+    pub fn square_free_factorization(&self) -> Vec<(Self, usize)> {
+        let f = self.monic();
+        if f.degree().unwrap_or(0) == 0 {
+            return vec![];
+        }
+
+        let derivative = f.derivative();
+        if derivative.is_zero() {
+            // f(x) = g(xᵖ) = g(x)ᵖ, since aᵖ = a in 𝔽ₚ; a nonconstant f only has
+            // a vanishing derivative if p ≤ deg f, so p fits into usize here
+            let p = M::MODULUS.as_usize();
+            let root = Self::new(f.coefficients.iter().step_by(p).cloned().collect());
+            return root
+                .square_free_factorization()
+                .into_iter()
+                .map(|(g, i)| (g, i * p))
+                .collect();
+        }
+
+        // Yun-style peeling: w collects the factors of multiplicity ≥ i, c the rest
+        let mut result = vec![];
+        let mut c = f.gcd(&derivative);
+        let mut w = f.exact_div(&c);
+        let mut i = 1;
+        while w.degree().unwrap_or(0) > 0 {
+            let y = w.gcd(&c);
+            let factor = w.exact_div(&y);
+            if factor.degree().unwrap_or(0) > 0 {
+                result.push((factor, i));
+            }
+            c = c.exact_div(&y);
+            w = y;
+            i += 1;
+        }
+
+        // What remains of c is a p-th power (its derivative vanishes),
+        // which the recursive call unwraps by taking the p-th root
+        if c.degree().unwrap_or(0) > 0 {
+            result.extend(c.square_free_factorization());
+        }
+        result
+    }
+
+    /// Distinct-degree factorization of a monic square-free polynomial:
+    /// returns `(gᵈ, d)` pairs where `gᵈ` is the product of all irreducible
+    /// factors of degree `d`.
+    pub fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+        let mut remaining = self.monic();
+        let x = Self::x();
+        let mut h = x.clone();
+        let mut result = vec![];
+        let mut d = 1;
+
+        while remaining.degree().unwrap_or(0) >= 2 * d {
+            // h = x^(pᵈ) mod remaining
+            h = Self::frobenius_mod(&h, 1, &remaining);
+            let g = remaining.gcd(&h.minus(&x));
+            if g != Self::one() {
+                remaining = remaining.exact_div(&g);
+                h = h.div_rem(&remaining).expect("remaining is nonzero").1;
+                result.push((g, d));
+            }
+            d += 1;
+        }
+
+        // A leftover factor of degree below 2d can only be irreducible
+        if let Some(degree) = remaining.degree().filter(|&degree| degree > 0) {
+            result.push((remaining, degree));
+        }
+        result
+    }
+
+    /// Equal-degree factorization (Cantor–Zassenhaus): splits a monic
+    /// square-free polynomial whose irreducible factors all have degree `d`
+    /// into those factors.
+    ///
+    /// The random trials are drawn from a fixed pseudo-random sequence,
+    /// so the result is reproducible; each trial splits `f` with
+    /// probability about 1/2.
+    pub fn equal_degree_factorization(&self, d: usize) -> Vec<Self> {
+        let f = self.monic();
+        let mut trials = TrialSequence::new(0);
+        let mut result = vec![];
+        f.equal_degree_split(d, &mut trials, &mut result);
+        result.sort_by(compare);
+        result
+    }
+
+    fn equal_degree_split(&self, d: usize, trials: &mut TrialSequence, result: &mut Vec<Self>) {
+        let n = self.degree().unwrap_or(0);
+        if n == 0 {
+            return;
+        }
+        if n <= d {
+            result.push(self.clone());
+            return;
+        }
+
+        loop {
+            let a = Self::new((0..n).map(|_| trials.next_below(&M::MODULUS)).collect());
+            if a.degree().unwrap_or(0) == 0 {
+                continue;
+            }
+
+            // a^((pᵈ − 1)/2) = N(a)^((p − 1)/2) with N(a) = a·aᵖ·…·a^(pᵈ⁻¹),
+            // which never needs pᵈ itself
+            let mut norm = a.clone();
+            let mut conjugate = a.clone();
+            for _ in 1..d {
+                conjugate = Self::frobenius_mod(&conjugate, 1, self);
+                norm = <Self as BinaryOperation<Multiplicative, Self>>::op(&norm, &conjugate)
+                    .div_rem(self)
+                    .expect("f is nonzero")
+                    .1;
+            }
+            let b = norm.pow_mod((M::MODULUS - 1) >> 1, self).expect("f is nonzero").minus(&Self::one());
+
+            let g = self.gcd(&b);
+            let degree = g.degree().unwrap_or(0);
+            if degree > 0 && degree < n {
+                g.equal_degree_split(d, trials, result);
+                self.exact_div(&g).equal_degree_split(d, trials, result);
+                return;
+            }
+        }
+    }
+
+    /// Factors the polynomial into monic irreducible factors:
+    /// returns `(gᵢ, eᵢ)` pairs with `f = lc(f)·∏ gᵢ^eᵢ`, sorted by degree.
+    ///
+    /// Combines square-free, distinct-degree and equal-degree factorization.
+    pub fn factor(&self) -> Vec<(Self, usize)> {
+        let mut result: Vec<(Self, usize)> = self
+            .square_free_factorization()
+            .into_iter()
+            .flat_map(|(part, multiplicity)| {
+                part.distinct_degree_factorization()
+                    .into_iter()
+                    .flat_map(move |(g, d)| g.equal_degree_factorization(d).into_iter().map(move |h| (h, multiplicity)))
+            })
+            .collect();
+        result.sort_by(|(a, _), (b, _)| compare(a, b));
+        result
+    }
+
+    /// Returns the distinct roots of the polynomial in 𝔽ₚ, in increasing order.
+    ///
+    /// The roots are those of `gcd(f, xᵖ − x)`, the product of the linear factors,
+    /// which is then split with Cantor–Zassenhaus.
+    pub fn roots(&self) -> Vec<U256> {
+        if self.degree().unwrap_or(0) == 0 {
+            return vec![];
+        }
+        let f = self.monic();
+        let x = Self::x();
+        let linear = f.gcd(&Self::frobenius_mod(&x, 1, &f).minus(&x));
+
+        let mut roots: Vec<U256> = linear
+            .equal_degree_factorization(1)
+            .into_iter()
+            // x − r is monic, so r = −c₀
+            .map(|g| {
+                <U256 as Invertible<Additive, Fp<M>>>::inverse(&g.coefficients[0])
+                    .expect("every field element has an additive inverse")
+            })
+            .collect();
+        roots.sort();
+        roots
+    }
+}
+
+/// Orders polynomials by degree, then by coefficients from the top down.
+fn compare<M: FieldModulus>(a: &Polynomial<Fp<M>>, b: &Polynomial<Fp<M>>) -> std::cmp::Ordering {
+    a.coefficients
+        .len()
+        .cmp(&b.coefficients.len())
+        .then_with(|| a.coefficients.iter().rev().cmp(b.coefficients.iter().rev()))
+}
+
+/// Returns the distinct prime divisors of `n`, by trial division.
+fn prime_divisors(mut n: usize) -> Vec<usize> {
+    let mut result = vec![];
+    let mut r = 2;
+    while r * r <= n {
+        if n.is_multiple_of(r) {
+            result.push(r);
+            while n.is_multiple_of(r) {
+                n /= r;
+            }
+        }
+        r += 1;
+    }
+    if n > 1 {
+        result.push(n);
+    }
+    result
+}
+
+/// A deterministic pseudo-random sequence (SplitMix64) used to pick
+/// Cantor–Zassenhaus trial polynomials.
+///
+/// The trials only need to avoid the small set of values that fail to split
+/// a polynomial, not to be unpredictable, so no external randomness is used.
+struct TrialSequence {
+    state: u64,
+}
+
+impl TrialSequence {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `[0, bound)`.
+    fn next_below(&mut self, bound: &U256) -> U256 {
+        U256([self.next_u64(), self.next_u64(), self.next_u64(), self.next_u64()]) % bound
+    }
+}
@@ -0,0 +1,334 @@
+use std::fmt;
+
+use crate::algebra::{
+    field::{
+        operation_helpers::{add, mul},
+        Field,
+    },
+    group::{element::GroupOps, Group},
+    monoid::{element::MonoidOps, Monoid},
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{Associative, Commutative, Distributive, Identity, Invertible},
+    ring::Ring,
+    semigroup::{SemiGroup, SemiGroupOps},
+    AlgebraicStructure,
+};
+
+use super::Polynomial;
+
+// -----------------------------------------------------------------------------
+// Implement AlgebraicStructure for Polynomial<F, T> (both for additive and multiplicative operations)
+// The polynomial type is its own element type, as U256 is for the additive monoid.
+// -----------------------------------------------------------------------------
+impl<F: Field<T>, T> AlgebraicStructure<Additive> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    type Element = Polynomial<F, T>;
+}
+
+impl<F: Field<T>, T> AlgebraicStructure<Multiplicative> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    type Element = Polynomial<F, T>;
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for addition (coefficient-wise)
+// -----------------------------------------------------------------------------
+impl<F: Field<T>, T> BinaryOperation<Additive, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    fn op(a: &Polynomial<F, T>, b: &Polynomial<F, T>) -> Polynomial<F, T> {
+        let (long, short) = if a.coefficients.len() >= b.coefficients.len() { (a, b) } else { (b, a) };
+        let mut coefficients = long.coefficients.clone();
+        for (c, s) in coefficients.iter_mut().zip(&short.coefficients) {
+            *c = add::<F, T>(c, s);
+        }
+        Polynomial::new(coefficients)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for multiplication (schoolbook convolution)
+// -----------------------------------------------------------------------------
+impl<F: Field<T>, T> BinaryOperation<Multiplicative, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    fn op(a: &Polynomial<F, T>, b: &Polynomial<F, T>) -> Polynomial<F, T> {
+        if a.is_zero() || b.is_zero() {
+            return Polynomial::zero();
+        }
+        let mut coefficients =
+            vec![<F as Identity<Additive, F>>::identity(); a.coefficients.len() + b.coefficients.len() - 1];
+        for (i, a_i) in a.coefficients.iter().enumerate() {
+            for (j, b_j) in b.coefficients.iter().enumerate() {
+                coefficients[i + j] = add::<F, T>(&coefficients[i + j], &mul::<F, T>(a_i, b_j));
+            }
+        }
+        Polynomial::new(coefficients)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Ring, Group, SemiGroup, Monoid, Distributive, Commutative, and Associative trait implementations
+// -----------------------------------------------------------------------------
+impl<F: Field<T>, T> Ring<Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+impl<F: Field<T>, T> Distributive<Polynomial<F, T>, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+impl<F: Field<T>, T> Commutative<Additive, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+impl<F: Field<T>, T> Commutative<Multiplicative, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+impl<F: Field<T>, T> Group<Additive> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+impl<F: Field<T>, T> SemiGroup<Multiplicative> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+impl<F: Field<T>, T> Monoid<Multiplicative> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+impl<F: Field<T>, T> Associative<Additive, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+impl<F: Field<T>, T> Associative<Multiplicative, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+impl<F: Field<T>, T> GroupOps<Additive, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+impl<F: Field<T>, T> SemiGroupOps<Multiplicative, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+impl<F: Field<T>, T> MonoidOps<Multiplicative, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{}
+
+// -----------------------------------------------------------------------------
+// Implement Invertible trait for the additive inverse.
+// (Only constants are units under multiplication, so no multiplicative inverse is provided.)
+// -----------------------------------------------------------------------------
+impl<F: Field<T>, T> Invertible<Additive, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    /// Negates every coefficient.
+    fn inverse(a: &Polynomial<F, T>) -> Option<Polynomial<F, T>> {
+        Some(Polynomial::new(
+            a.coefficients
+                .iter()
+                .map(|c| {
+                    <T as Invertible<Additive, F>>::inverse(c)
+                        .expect("every field element has an additive inverse")
+                })
+                .collect(),
+        ))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement Identity trait to provide the neutral elements.
+// -----------------------------------------------------------------------------
+impl<F: Field<T>, T> Identity<Additive, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    /// Returns the zero polynomial.
+    fn identity() -> Polynomial<F, T> {
+        Polynomial::zero()
+    }
+}
+
+impl<F: Field<T>, T> Identity<Multiplicative, Polynomial<F, T>> for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    /// Returns the constant polynomial `1`.
+    fn identity() -> Polynomial<F, T> {
+        Polynomial::one()
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+
+use crate::algebra::{
+    field::{
+        operation_helpers::{add, batch_inverse, mul, sub},
+        Field,
+    },
+    group::element::GroupOps,
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{Commutative, Distributive, Identity},
+};
+
+use super::Polynomial;
+
+impl<F: Field<T>, T> Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    /// Returns the vanishing polynomial `Z(x) = (x − x₀)(x − x₁)…(x − xₙ₋₁)`,
+    /// which is zero exactly on the given points.
+    pub fn vanishing(points: &[T]) -> Self {
+        let one = <F as Identity<Multiplicative, F>>::identity();
+        let mut coefficients = vec![one];
+        for x in points {
+            // Multiply by (x − xᵢ): shift up by one degree and subtract xᵢ times the old value
+            coefficients.insert(0, <F as Identity<Additive, F>>::identity());
+            for i in 0..coefficients.len() - 1 {
+                coefficients[i] = sub::<F, T>(&coefficients[i], &mul::<F, T>(x, &coefficients[i + 1]));
+            }
+        }
+        Self::new(coefficients)
+    }
+
+    /// Returns the unique polynomial of degree below `n` passing through the
+    /// `n` given `(x, y)` points (Lagrange interpolation).
+    ///
+    /// The Lagrange basis is built from the vanishing polynomial `Z` of all
+    /// `x` coordinates: `Lᵢ(x) = Z(x) / ((x − xᵢ)·Z'(xᵢ))`. All denominators
+    /// are inverted together with [`batch_inverse`], so the whole
+    /// interpolation costs `O(n²)` field operations and one inversion.
+    ///
+    /// Returns None if two points share the same `x` coordinate.
+    pub fn interpolate(points: &[(T, T)]) -> Option<Self> {
+        let zero = <F as Identity<Additive, F>>::identity();
+        let xs: Vec<T> = points.iter().map(|(x, _)| x.clone()).collect();
+        let vanishing = Self::vanishing(&xs);
+
+        // Z(x) / (x − xᵢ) by synthetic division, from the top coefficient down
+        let quotients: Vec<Vec<T>> = xs
+            .iter()
+            .map(|x| {
+                let mut quotient = vec![zero.clone(); xs.len()];
+                let mut carry = zero.clone();
+                for i in (0..xs.len()).rev() {
+                    carry = add::<F, T>(&vanishing.coefficients[i + 1], &mul::<F, T>(&carry, x));
+                    quotient[i] = carry.clone();
+                }
+                quotient
+            })
+            .collect();
+
+        // Z'(xᵢ) = ∏_{j ≠ i} (xᵢ − xⱼ), which vanishes exactly for repeated coordinates
+        let denominators: Vec<T> = quotients
+            .iter()
+            .zip(&xs)
+            .map(|(quotient, x)| Self::new(quotient.clone()).evaluate(x))
+            .collect();
+        let inverses = batch_inverse::<F, T>(&denominators);
+
+        let mut coefficients = vec![zero; xs.len()];
+        for ((quotient, (_, y)), inverse) in quotients.iter().zip(points).zip(inverses) {
+            let weight = mul::<F, T>(y, &inverse?);
+            for (c, q) in coefficients.iter_mut().zip(quotient) {
+                *c = add::<F, T>(c, &mul::<F, T>(&weight, q));
+            }
+        }
+        Some(Self::new(coefficients))
+    }
+
+    /// Evaluates the polynomial at every point in `points`.
+    pub fn evaluate_many(&self, points: &[T]) -> Vec<T> {
+        points.iter().map(|x| self.evaluate(x)).collect()
+    }
+}
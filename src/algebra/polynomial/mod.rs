@@ -0,0 +1,401 @@
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+use primitive_types::U256;
+
+use super::{
+    field::{
+        operation_helpers::{add, mul, sub},
+        Field,
+    },
+    group::{element::GroupOps, extension::Power},
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{Commutative, Distributive, Identity, Invertible},
+};
+
+pub mod factorization;
+pub mod impls;
+pub mod interpolation;
+pub mod multiplication;
+
+/// A univariate polynomial `c₀ + c₁·x + … + cₙ·xⁿ` with coefficients in the field `F`.
+///
+/// Coefficients are stored lowest degree first and kept normalized, i.e.
+/// without trailing zeros, so the zero polynomial has no coefficients and
+/// structural equality coincides with polynomial equality.
+///
+/// `Polynomial<F, T>` is its own algebraic structure (like `U256` under
+/// addition): it implements `Ring` with itself as the element type.
+pub struct Polynomial<F, T = U256> {
+    coefficients: Vec<T>,
+    _field: PhantomData<F>,
+}
+
+impl<F, T> Polynomial<F, T> {
+    /// Returns the coefficients, lowest degree first.
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Returns `true` for the zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Returns the degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Returns the coefficient of the highest power of `x`, or `None` for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Option<&T> {
+        self.coefficients.last()
+    }
+}
+
+impl<F: Field<T>, T> Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    /// Creates a polynomial from its coefficients, lowest degree first,
+    /// reducing each coefficient into the field and dropping trailing zeros.
+    pub fn new(coefficients: Vec<T>) -> Self {
+        let zero = <F as Identity<Additive, F>>::identity();
+        // Adding zero maps non-canonical representatives (e.g. `p + 1`) to their residue
+        let mut coefficients: Vec<T> = coefficients.iter().map(|c| add::<F, T>(c, &zero)).collect();
+        while coefficients.last() == Some(&zero) {
+            coefficients.pop();
+        }
+        Self { coefficients, _field: PhantomData }
+    }
+
+    /// Returns the zero polynomial.
+    pub fn zero() -> Self {
+        Self::new(vec![])
+    }
+
+    /// Returns the constant polynomial `1`.
+    pub fn one() -> Self {
+        Self::constant(<F as Identity<Multiplicative, F>>::identity())
+    }
+
+    /// Returns the constant polynomial `c`.
+    pub fn constant(c: T) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// Returns the monomial `c·xⁿ`.
+    pub fn monomial(c: T, degree: usize) -> Self {
+        let mut coefficients = vec![<F as Identity<Additive, F>>::identity(); degree];
+        coefficients.push(c);
+        Self::new(coefficients)
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's rule.
+    pub fn evaluate(&self, x: &T) -> T {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(<F as Identity<Additive, F>>::identity(), |acc, c| add::<F, T>(&mul::<F, T>(&acc, x), c))
+    }
+
+    /// Multiplies every coefficient by the scalar `c`.
+    pub fn scale(&self, c: &T) -> Self {
+        Self::new(self.coefficients.iter().map(|a| mul::<F, T>(a, c)).collect())
+    }
+
+    /// Divides by the leading coefficient, so that the result is monic.
+    /// The zero polynomial is returned unchanged.
+    pub fn monic(&self) -> Self {
+        match self.leading_coefficient() {
+            Some(lead) => {
+                let lead_inv = <T as Invertible<Multiplicative, F>>::inverse(lead)
+                    .expect("the leading coefficient is nonzero");
+                self.scale(&lead_inv)
+            }
+            None => Self::zero(),
+        }
+    }
+
+    /// Euclidean division: returns `(q, r)` with `self = q·divisor + r`
+    /// and `deg r < deg divisor`.
+    ///
+    /// Returns None if the divisor is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> Option<(Self, Self)> {
+        let divisor_degree = divisor.degree()?;
+        let lead_inv = <T as Invertible<Multiplicative, F>>::inverse(divisor.leading_coefficient()?)?;
+
+        let mut remainder = self.coefficients.clone();
+        let Some(quotient_len) = (remainder.len() + 1).checked_sub(divisor.coefficients.len()) else {
+            return Some((Self::zero(), self.clone()));
+        };
+        let mut quotient = vec![<F as Identity<Additive, F>>::identity(); quotient_len];
+
+        // Cancel the leading term of the remainder, from the top degree down
+        for i in (0..quotient_len).rev() {
+            let factor = mul::<F, T>(&remainder[i + divisor_degree], &lead_inv);
+            for (j, d) in divisor.coefficients.iter().enumerate() {
+                remainder[i + j] = sub::<F, T>(&remainder[i + j], &mul::<F, T>(&factor, d));
+            }
+            quotient[i] = factor;
+        }
+        remainder.truncate(divisor_degree);
+
+        Some((Self::new(quotient), Self::new(remainder)))
+    }
+
+    /// Returns the monic greatest common divisor computed with the Euclidean algorithm.
+    /// The gcd of two zero polynomials is the zero polynomial.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b).expect("the divisor is nonzero");
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+
+    /// Computes `self^exp mod modulus` by square-and-multiply,
+    /// reducing after every step so intermediate degrees stay below `deg modulus`.
+    ///
+    /// Returns None if the modulus is the zero polynomial.
+    pub fn pow_mod(&self, exp: U256, modulus: &Self) -> Option<Self> {
+        let base = self.div_rem(modulus)?.1;
+        let mut acc = Self::one().div_rem(modulus)?.1;
+        for i in (0..exp.bits()).rev() {
+            acc = <Self as BinaryOperation<Multiplicative, Self>>::op(&acc, &acc).div_rem(modulus)?.1;
+            if exp.bit(i) {
+                acc = <Self as BinaryOperation<Multiplicative, Self>>::op(&acc, &base).div_rem(modulus)?.1;
+            }
+        }
+        Some(acc)
+    }
+
+    /// Returns the formal derivative `Σ i·cᵢ·xⁱ⁻¹`.
+    ///
+    /// The integer factor `i` is applied as repeated addition in `F`,
+    /// so it is reduced modulo the characteristic.
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| <F as Power<Additive, F>>::pow(c, U256::from(i)))
+                .collect(),
+        )
+    }
+
+    /// Returns the composition `self(inner(x))`, evaluated with Horner's rule.
+    pub fn compose(&self, inner: &Self) -> Self {
+        self.coefficients.iter().rev().fold(Self::zero(), |acc, c| {
+            <Self as BinaryOperation<Additive, Self>>::op(
+                &<Self as BinaryOperation<Multiplicative, Self>>::op(&acc, inner),
+                &Self::constant(c.clone()),
+            )
+        })
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Standard trait implementations (written by hand, since the field marker
+// types themselves implement none of Clone, PartialEq)
+// -----------------------------------------------------------------------------
+impl<F, T: Clone> Clone for Polynomial<F, T> {
+    fn clone(&self) -> Self {
+        Self { coefficients: self.coefficients.clone(), _field: PhantomData }
+    }
+}
+
+impl<F, T: PartialEq> PartialEq for Polynomial<F, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coefficients == other.coefficients
+    }
+}
+
+impl<F, T: Eq> Eq for Polynomial<F, T> {}
+
+impl<F, T: fmt::Debug> fmt::Debug for Polynomial<F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Polynomial").field(&self.coefficients).finish()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Arithmetic operators
+//
+// Each binary operator is implemented for owned values and references,
+// together with its assignment variant.
+// -----------------------------------------------------------------------------
+macro_rules! impl_binary_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:expr) => {
+        impl<F: Field<T>, T> $trait<&Polynomial<F, T>> for &Polynomial<F, T>
+        where
+            T: Clone
+                + fmt::Debug
+                + GroupOps<Additive, F>
+                + GroupOps<Multiplicative, F>
+                + BinaryOperation<Additive, F>
+                + BinaryOperation<Multiplicative, F>
+                + Commutative<Additive, F>
+                + Commutative<Multiplicative, F>
+                + Distributive<F, T>,
+        {
+            type Output = Polynomial<F, T>;
+
+            fn $method(self, rhs: &Polynomial<F, T>) -> Polynomial<F, T> {
+                $op(self, rhs)
+            }
+        }
+
+        impl<F: Field<T>, T> $trait for Polynomial<F, T>
+        where
+            T: Clone
+                + fmt::Debug
+                + GroupOps<Additive, F>
+                + GroupOps<Multiplicative, F>
+                + BinaryOperation<Additive, F>
+                + BinaryOperation<Multiplicative, F>
+                + Commutative<Additive, F>
+                + Commutative<Multiplicative, F>
+                + Distributive<F, T>,
+        {
+            type Output = Polynomial<F, T>;
+
+            fn $method(self, rhs: Polynomial<F, T>) -> Polynomial<F, T> {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<F: Field<T>, T> $assign_trait for Polynomial<F, T>
+        where
+            T: Clone
+                + fmt::Debug
+                + GroupOps<Additive, F>
+                + GroupOps<Multiplicative, F>
+                + BinaryOperation<Additive, F>
+                + BinaryOperation<Multiplicative, F>
+                + Commutative<Additive, F>
+                + Commutative<Multiplicative, F>
+                + Distributive<F, T>,
+        {
+            fn $assign_method(&mut self, rhs: Polynomial<F, T>) {
+                *self = $op(self, &rhs);
+            }
+        }
+    };
+}
+
+impl_binary_operator!(Add, add, AddAssign, add_assign, <Polynomial<F, T> as BinaryOperation<Additive, Polynomial<F, T>>>::op);
+impl_binary_operator!(Sub, sub, SubAssign, sub_assign, polynomial_sub);
+impl_binary_operator!(Mul, mul, MulAssign, mul_assign, <Polynomial<F, T> as BinaryOperation<Multiplicative, Polynomial<F, T>>>::op);
+impl_binary_operator!(Div, div, DivAssign, div_assign, polynomial_div);
+impl_binary_operator!(Rem, rem, RemAssign, rem_assign, polynomial_rem);
+
+/// Subtraction adds the additive inverse of the subtrahend.
+fn polynomial_sub<F: Field<T>, T>(a: &Polynomial<F, T>, b: &Polynomial<F, T>) -> Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    <Polynomial<F, T> as BinaryOperation<Additive, Polynomial<F, T>>>::op(a, &-b)
+}
+
+/// Returns the quotient of Euclidean division.
+///
+/// # Panics
+///
+/// Panics if the divisor is the zero polynomial, mirroring integer division.
+fn polynomial_div<F: Field<T>, T>(a: &Polynomial<F, T>, b: &Polynomial<F, T>) -> Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    a.div_rem(b).expect("division by the zero polynomial").0
+}
+
+/// Returns the remainder of Euclidean division.
+///
+/// # Panics
+///
+/// Panics if the divisor is the zero polynomial, mirroring integer division.
+fn polynomial_rem<F: Field<T>, T>(a: &Polynomial<F, T>, b: &Polynomial<F, T>) -> Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    a.div_rem(b).expect("division by the zero polynomial").1
+}
+
+impl<F: Field<T>, T> Neg for &Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    type Output = Polynomial<F, T>;
+
+    fn neg(self) -> Polynomial<F, T> {
+        <Polynomial<F, T> as Invertible<Additive, Polynomial<F, T>>>::inverse(self)
+            .expect("every polynomial has an additive inverse")
+    }
+}
+
+impl<F: Field<T>, T> Neg for Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    type Output = Polynomial<F, T>;
+
+    fn neg(self) -> Polynomial<F, T> {
+        -&self
+    }
+}
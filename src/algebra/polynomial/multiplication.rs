@@ -0,0 +1,56 @@
+use std::fmt;
+
+use crate::algebra::{
+    field::{
+        ntt::{intt, ntt},
+        operation_helpers::mul,
+        TwoAdicField,
+    },
+    group::element::GroupOps,
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{Commutative, Distributive, Identity},
+};
+
+use super::Polynomial;
+
+impl<F: TwoAdicField<T>, T> Polynomial<F, T>
+where
+    T: Clone
+        + fmt::Debug
+        + GroupOps<Additive, F>
+        + GroupOps<Multiplicative, F>
+        + BinaryOperation<Additive, F>
+        + BinaryOperation<Multiplicative, F>
+        + Commutative<Additive, F>
+        + Commutative<Multiplicative, F>
+        + Distributive<F, T>,
+{
+    /// Multiplies two polynomials in `O(n log n)` via the NTT:
+    /// both operands are evaluated on a power-of-two domain large enough
+    /// for the product, multiplied pointwise and interpolated back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the product needs a domain larger than `2^TWO_ADICITY`.
+    pub fn mul_ntt(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        let size = (self.coefficients.len() + other.coefficients.len() - 1).next_power_of_two();
+        let zero = <F as Identity<Additive, F>>::identity();
+
+        let mut a = self.coefficients.clone();
+        a.resize(size, zero.clone());
+        let mut b = other.coefficients.clone();
+        b.resize(size, zero);
+
+        ntt::<F, T>(&mut a);
+        ntt::<F, T>(&mut b);
+        for (x, y) in a.iter_mut().zip(&b) {
+            *x = mul::<F, T>(x, y);
+        }
+        intt::<F, T>(&mut a);
+
+        Self::new(a)
+    }
+}
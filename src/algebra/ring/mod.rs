@@ -1,5 +1,7 @@
 use super::{group::{element::GroupOps, AbelianGroup}, operations::{Additive, BinaryOperation, Multiplicative}, properties::{Commutative, Distributive}, semigroup::{SemiGroup, SemiGroupOps}, AlgebraicStructure};
 
+pub mod zn;
+
 pub trait Ring<T>: 
     AbelianGroup<Additive>
     + SemiGroup<Multiplicative>
@@ -0,0 +1,216 @@
+// -----------------------------------------------------------------------------
+// External and internal module imports
+// -----------------------------------------------------------------------------
+use std::marker::PhantomData;
+
+use primitive_types::U256;
+
+use crate::{
+    algebra::{
+        group::{element::GroupOps, Group},
+        monoid::{element::MonoidOps, Monoid},
+        operations::{Additive, BinaryOperation, Multiplicative},
+        properties::{Associative, Commutative, Distributive, Finite, Identity, Invertible},
+        semigroup::{SemiGroup, SemiGroupOps},
+        AlgebraicStructure,
+    },
+    helpers::{add_mod, gcd, mod_inverse, mul_mod, sub_mod},
+};
+
+use super::Ring;
+
+/// A trait describing the modulus `n` of the ring of integers modulo `n`.
+///
+/// Unlike [`FieldModulus`](crate::algebra::field::fp::FieldModulus),
+/// `n` may be composite (e.g. an RSA modulus) or even, so arithmetic uses
+/// plain widening reduction instead of Montgomery multiplication.
+pub trait RingModulus: std::fmt::Debug {
+    /// The modulus `n > 1`.
+    const MODULUS: U256;
+}
+
+/// Declares a new ring of integers modulo `n` in one line.
+///
+/// Expands to a modulus marker type implementing [`RingModulus`] and a type
+/// alias for the corresponding [`Zn`] instantiation:
+///
+/// ```ignore
+/// integer_ring!(pub Z_15, Z15Modulus, U256([15, 0, 0, 0]));
+/// ```
+#[macro_export]
+macro_rules! integer_ring {
+    ($vis:vis $name:ident, $modulus:ident, $value:expr) => {
+        #[derive(Debug)]
+        $vis struct $modulus;
+
+        impl $crate::algebra::ring::zn::RingModulus for $modulus {
+            const MODULUS: ::primitive_types::U256 = $value;
+        }
+
+        $vis type $name = $crate::algebra::ring::zn::Zn<$modulus>;
+    };
+}
+
+// -----------------------------------------------------------------------------
+// Definition of the ring Zn<N> (integers modulo N::MODULUS)
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub struct Zn<N> {
+    _modulus: PhantomData<N>,
+}
+
+// Written by hand, since the derive would require `N: Default`.
+impl<N> Default for Zn<N> {
+    fn default() -> Self {
+        Self { _modulus: PhantomData }
+    }
+}
+
+impl<N: RingModulus> Zn<N> {
+    /// Returns `true` if `a` is a unit, i.e. `gcd(a, n) = 1`.
+    pub fn is_unit(a: &U256) -> bool {
+        gcd(*a % N::MODULUS, N::MODULUS) == U256::one()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement AlgebraicStructure for Zn<N> (both for additive and multiplicative operations)
+// -----------------------------------------------------------------------------
+impl<N: RingModulus> AlgebraicStructure<Additive> for Zn<N> {
+    type Element = U256;
+}
+
+impl<N: RingModulus> AlgebraicStructure<Multiplicative> for Zn<N> {
+    type Element = U256;
+}
+
+// -----------------------------------------------------------------------------
+// Implement BinaryOperation for addition and multiplication modulo n
+// -----------------------------------------------------------------------------
+impl<N: RingModulus> BinaryOperation<Additive, Zn<N>> for U256 {
+    fn op(a: &U256, b: &U256) -> U256 {
+        add_mod(*a, *b, N::MODULUS)
+    }
+}
+
+impl<N: RingModulus> BinaryOperation<Multiplicative, Zn<N>> for U256 {
+    fn op(a: &U256, b: &U256) -> U256 {
+        mul_mod(*a, *b, N::MODULUS)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement the Finite trait for Zn<N>
+// -----------------------------------------------------------------------------
+impl<N: RingModulus> Finite for Zn<N> {
+    /// Returns the number of residues, which is the modulus n.
+    fn order(&self) -> U256 {
+        N::MODULUS
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Ring, Group, SemiGroup, Monoid, Distributive, Commutative, and Associative trait implementations
+// -----------------------------------------------------------------------------
+impl<N: RingModulus> Ring<U256> for Zn<N> {}
+
+impl<N: RingModulus> Distributive<Zn<N>, U256> for Zn<N> {}
+impl<N: RingModulus> Distributive<Zn<N>, U256> for U256 {}
+
+impl<N: RingModulus> Commutative<Additive, Zn<N>> for U256 {}
+impl<N: RingModulus> Commutative<Multiplicative, Zn<N>> for U256 {}
+
+impl<N: RingModulus> Group<Additive> for Zn<N> {}
+impl<N: RingModulus> SemiGroup<Multiplicative> for Zn<N> {}
+impl<N: RingModulus> Monoid<Multiplicative> for Zn<N> {}
+
+impl<N: RingModulus> Associative<Additive, Zn<N>> for U256 {}
+impl<N: RingModulus> Associative<Multiplicative, Zn<N>> for U256 {}
+
+impl<N: RingModulus> GroupOps<Additive, Zn<N>> for U256 {}
+impl<N: RingModulus> SemiGroupOps<Multiplicative, Zn<N>> for U256 {}
+impl<N: RingModulus> MonoidOps<Multiplicative, Zn<N>> for U256 {}
+
+// -----------------------------------------------------------------------------
+// Implement Invertible trait for finding multiplicative and additive inverses.
+// -----------------------------------------------------------------------------
+impl<N: RingModulus> Invertible<Multiplicative, Zn<N>> for U256 {
+    /// Computes the multiplicative inverse of a modulo n.
+    /// Returns None if a is not a unit, i.e. gcd(a, n) ≠ 1.
+    fn inverse(a: &U256) -> Option<U256> {
+        mod_inverse(*a, N::MODULUS)
+    }
+}
+
+impl<N: RingModulus> Invertible<Additive, Zn<N>> for U256 {
+    /// Computes the additive inverse (n - a) mod n.
+    fn inverse(a: &U256) -> Option<U256> {
+        Some(sub_mod(U256::zero(), *a, N::MODULUS))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Implement Identity trait to provide the neutral elements.
+// -----------------------------------------------------------------------------
+impl<N: RingModulus> Identity<Multiplicative, Zn<N>> for Zn<N> {
+    /// Returns the multiplicative identity (1) in Zn<N>.
+    fn identity() -> U256 {
+        U256::one()
+    }
+}
+
+impl<N: RingModulus> Identity<Additive, Zn<N>> for Zn<N> {
+    /// Returns the additive identity (0) in Zn<N>.
+    fn identity() -> U256 {
+        U256::zero()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Definition of the unit group Units<N> = (ℤ/nℤ)*
+//
+// Its elements are the residues coprime to n; closure holds because a product
+// of units is a unit. Elements are not validated on every operation, use
+// `Zn::is_unit` to check inputs.
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+pub struct Units<N> {
+    _modulus: PhantomData<N>,
+}
+
+// Written by hand, since the derive would require `N: Default`.
+impl<N> Default for Units<N> {
+    fn default() -> Self {
+        Self { _modulus: PhantomData }
+    }
+}
+
+impl<N: RingModulus> AlgebraicStructure<Multiplicative> for Units<N> {
+    type Element = U256;
+}
+
+impl<N: RingModulus> BinaryOperation<Multiplicative, Units<N>> for U256 {
+    fn op(a: &U256, b: &U256) -> U256 {
+        mul_mod(*a, *b, N::MODULUS)
+    }
+}
+
+impl<N: RingModulus> Group<Multiplicative> for Units<N> {}
+impl<N: RingModulus> Commutative<Multiplicative, Units<N>> for U256 {}
+impl<N: RingModulus> Associative<Multiplicative, Units<N>> for U256 {}
+impl<N: RingModulus> GroupOps<Multiplicative, Units<N>> for U256 {}
+
+impl<N: RingModulus> Invertible<Multiplicative, Units<N>> for U256 {
+    /// Computes the inverse of a unit modulo n.
+    /// Returns None if a is not actually a unit.
+    fn inverse(a: &U256) -> Option<U256> {
+        mod_inverse(*a, N::MODULUS)
+    }
+}
+
+impl<N: RingModulus> Identity<Multiplicative, Units<N>> for Units<N> {
+    /// Returns the identity (1) of the unit group.
+    fn identity() -> U256 {
+        U256::one()
+    }
+}
@@ -0,0 +1,151 @@
+use std::hint::black_box;
+
+use primitive_types::U256;
+
+/// The result of a constant-time comparison, stored as a mask
+/// that is either all zeros (`false`) or all ones (`true`).
+///
+/// Conditions on secret data are carried around as a `Choice`
+/// and consumed through mask arithmetic instead of `if` branches.
+#[derive(Clone, Copy, Debug)]
+pub struct Choice(u64);
+
+impl Choice {
+    /// Creates a `Choice` from a bit that must be either `0` or `1`.
+    #[inline]
+    pub fn from_bit(bit: u64) -> Self {
+        // `black_box` keeps the optimizer from turning the mask back into a branch.
+        Self(black_box(bit.wrapping_neg()))
+    }
+
+    /// Returns the underlying mask (`0` or `u64::MAX`).
+    #[inline]
+    pub fn mask(self) -> u64 {
+        self.0
+    }
+
+    /// Returns `true` if the choice is set.
+    ///
+    /// This leaves the constant-time domain and should only be used
+    /// on values that are allowed to become public.
+    #[inline]
+    pub fn is_set(self) -> bool {
+        self.0 != 0
+    }
+}
+
+impl std::ops::BitAnd for Choice {
+    type Output = Choice;
+
+    fn bitand(self, rhs: Choice) -> Choice {
+        Choice(self.0 & rhs.0)
+    }
+}
+
+impl std::ops::BitOr for Choice {
+    type Output = Choice;
+
+    fn bitor(self, rhs: Choice) -> Choice {
+        Choice(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::Not for Choice {
+    type Output = Choice;
+
+    fn not(self) -> Choice {
+        Choice(!self.0)
+    }
+}
+
+/// A trait for types whose values can be selected and swapped
+/// based on a secret [`Choice`] without branching.
+pub trait ConditionallySelectable: Sized {
+    /// Returns `a` if `choice` is unset and `b` if it is set.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self;
+
+    /// Swaps `a` and `b` if `choice` is set.
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        let new_a = Self::conditional_select(a, b, choice);
+        let new_b = Self::conditional_select(b, a, choice);
+        *a = new_a;
+        *b = new_b;
+    }
+}
+
+impl ConditionallySelectable for u64 {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        a ^ (choice.mask() & (a ^ b))
+    }
+}
+
+impl ConditionallySelectable for i64 {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        u64::conditional_select(&(*a as u64), &(*b as u64), choice) as i64
+    }
+}
+
+impl ConditionallySelectable for U256 {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = u64::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        U256(limbs)
+    }
+}
+
+/// Returns a set `Choice` if `a == b`, comparing every limb.
+pub fn ct_eq(a: &U256, b: &U256) -> Choice {
+    let mut diff = 0u64;
+    for i in 0..4 {
+        diff |= a.0[i] ^ b.0[i];
+    }
+    ct_is_zero_word(diff)
+}
+
+/// Returns a set `Choice` if `a == 0`.
+pub fn ct_is_zero(a: &U256) -> Choice {
+    ct_is_zero_word(a.0[0] | a.0[1] | a.0[2] | a.0[3])
+}
+
+/// Returns a set `Choice` if the word `x` is zero.
+#[inline]
+fn ct_is_zero_word(x: u64) -> Choice {
+    // The top bit of `x | -x` is set exactly when `x != 0`.
+    Choice::from_bit(((x | x.wrapping_neg()) >> 63) ^ 1)
+}
+
+/// Computes `(a + b) mod m` for `a, b < m` without secret-dependent branches.
+pub fn ct_add_mod(a: &U256, b: &U256, m: &U256) -> U256 {
+    let (sum, carry) = a.overflowing_add(*b);
+    let (reduced, borrow) = sum.overflowing_sub(*m);
+    // Keep the reduced value if the sum carried out or did not underflow.
+    let use_reduced = Choice::from_bit(carry as u64) | !Choice::from_bit(borrow as u64);
+    U256::conditional_select(&sum, &reduced, use_reduced)
+}
+
+/// Computes `(a - b) mod m` for `a, b < m` without secret-dependent branches.
+pub fn ct_sub_mod(a: &U256, b: &U256, m: &U256) -> U256 {
+    let (diff, borrow) = a.overflowing_sub(*b);
+    // On underflow the wrapped difference plus `m` is the residue.
+    U256::conditional_select(&diff, &diff.overflowing_add(*m).0, Choice::from_bit(borrow as u64))
+}
+
+/// Computes `(-a) mod m` for `a < m` without secret-dependent branches.
+pub fn ct_neg_mod(a: &U256, m: &U256) -> U256 {
+    let negated = m.overflowing_sub(*a).0;
+    U256::conditional_select(&negated, &U256::zero(), ct_is_zero(a))
+}
+
+/// Computes `a · 2⁻¹ mod m` for odd `m` and `a < m` without secret-dependent branches.
+pub fn ct_half_mod(a: &U256, m: &U256) -> U256 {
+    // For odd `a`, `a + m` is even; the carry becomes the top bit after shifting.
+    let (sum, carry) = a.overflowing_add(*m);
+    let mut odd_half = sum >> 1;
+    odd_half.0[3] |= (carry as u64) << 63;
+    U256::conditional_select(&(*a >> 1), &odd_half, Choice::from_bit(a.0[0] & 1))
+}
@@ -1,4 +1,6 @@
-use primitive_types::U256;
+use primitive_types::{U256, U512};
+
+pub mod constant_time;
 
 /// A struct representing a range over `U256` values.
 /// Implements the `Iterator` trait, yielding values from `current` up to (but not including) `end`.
@@ -42,16 +44,135 @@ pub fn gcd(a: U256, b: U256) -> U256 {
     a
 }
 
+/// Computes `(a + b) mod m` without overflowing `U256`.
+///
+/// The sum is formed with carry detection, so operands close to `2²⁵⁶`
+/// (e.g. elements of the secp256k1 base field) are handled correctly.
+pub fn add_mod(a: U256, b: U256, m: U256) -> U256 {
+    let a = if a >= m { a % m } else { a };
+    let b = if b >= m { b % m } else { b };
+    let (sum, carry) = a.overflowing_add(b);
+    // If the addition carried out of 256 bits, the true sum is `sum + 2²⁵⁶ >= m`,
+    // and the wrapping subtraction below yields the correct residue.
+    if carry || sum >= m {
+        sum.overflowing_sub(m).0
+    } else {
+        sum
+    }
+}
+
+/// Computes `(a - b) mod m` without underflowing `U256`.
+pub fn sub_mod(a: U256, b: U256, m: U256) -> U256 {
+    let a = if a >= m { a % m } else { a };
+    let b = if b >= m { b % m } else { b };
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+/// Computes `(a * b) mod m` through a widening 512-bit product,
+/// so the multiplication never overflows regardless of the operand size.
+pub fn mul_mod(a: U256, b: U256, m: U256) -> U256 {
+    let product: U512 = a.full_mul(b);
+    let reduced = product % U512::from(m);
+    // The remainder is strictly smaller than `m`, so it always fits into 256 bits.
+    U256::try_from(reduced).expect("remainder modulo a U256 value fits into U256")
+}
+
+/// Computes the Jacobi symbol `(a / n)` for an odd modulus `n`.
+///
+/// Returns `1`, `-1`, or `0` (when `gcd(a, n) > 1`). For a prime `n` this is
+/// the Legendre symbol: `1` for nonzero quadratic residues, `-1` for non-residues.
+/// The computation uses quadratic reciprocity and never exponentiates.
+pub fn jacobi(a: U256, n: U256) -> i8 {
+    assert!(n.bit(0), "the Jacobi symbol is only defined for odd moduli");
+    let mut a = a % n;
+    let mut n = n;
+    let mut result = 1i8;
+
+    while !a.is_zero() {
+        // Pull out factors of two: (2 / n) = -1 exactly when n ≡ 3, 5 (mod 8).
+        let twos = a.trailing_zeros();
+        a >>= twos as usize;
+        let n_mod_8 = n.low_u64() & 7;
+        if twos % 2 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+            result = -result;
+        }
+
+        // Quadratic reciprocity flips the sign when both are ≡ 3 (mod 4).
+        std::mem::swap(&mut a, &mut n);
+        if a.low_u64() & 3 == 3 && n.low_u64() & 3 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+
+    if n == U256::one() { result } else { 0 }
+}
+
+/// A signed integer with a 256-bit magnitude.
+///
+/// Used for Bézout coefficients, which may be negative and are bounded
+/// in absolute value by the inputs of the Extended Euclidean Algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignedU256 {
+    pub negative: bool,
+    pub magnitude: U256,
+}
+
+impl SignedU256 {
+    /// Creates a non-negative value.
+    pub fn positive(magnitude: U256) -> Self {
+        Self { negative: false, magnitude }
+    }
+
+    /// Returns `self + other`.
+    pub fn add(&self, other: &Self) -> Self {
+        if self.negative == other.negative {
+            return Self { negative: self.negative, magnitude: self.magnitude + other.magnitude };
+        }
+        // Opposite signs: the result takes the sign of the larger magnitude.
+        if self.magnitude >= other.magnitude {
+            Self::normalized(self.negative, self.magnitude - other.magnitude)
+        } else {
+            Self::normalized(other.negative, other.magnitude - self.magnitude)
+        }
+    }
+
+    /// Returns `self - q·other` for a non-negative factor `q`.
+    pub fn sub_mul(&self, q: U256, other: &Self) -> Self {
+        let product = Self::normalized(!other.negative, q * other.magnitude);
+        self.add(&product)
+    }
+
+    /// Returns the representative of `self` in the range `[0, m - 1]`.
+    pub fn rem_euclid(&self, m: U256) -> U256 {
+        let r = self.magnitude % m;
+        if self.negative && !r.is_zero() { m - r } else { r }
+    }
+
+    /// Keeps zero non-negative, so that equal values compare equal.
+    fn normalized(negative: bool, magnitude: U256) -> Self {
+        Self { negative: negative && !magnitude.is_zero(), magnitude }
+    }
+}
+
 /// Extended Euclidean Algorithm.
 /// Returns a triple `(g, x, y)`, where `g = gcd(a, b)` and `x`, `y` satisfy the equation:
 /// `a * x + b * y = g`.
-pub fn extended_gcd(a: U256, b: U256) -> (U256, i128, i128) {
+///
+/// The coefficients are tracked as [`SignedU256`] values, so the algorithm is
+/// correct over the full `U256` range: `|x| <= b` and `|y| <= a` always hold,
+/// and no intermediate product overflows.
+pub fn extended_gcd(a: U256, b: U256) -> (U256, SignedU256, SignedU256) {
     let mut r0 = a;
     let mut r1 = b;
-    let mut s0: i128 = 1;
-    let mut s1: i128 = 0;
-    let mut t0: i128 = 0;
-    let mut t1: i128 = 1;
+    let mut s0 = SignedU256::positive(U256::one());
+    let mut s1 = SignedU256::positive(U256::zero());
+    let mut t0 = SignedU256::positive(U256::zero());
+    let mut t1 = SignedU256::positive(U256::one());
 
     while r1 != U256::zero() {
         let q = r0 / r1;
@@ -59,14 +180,11 @@ pub fn extended_gcd(a: U256, b: U256) -> (U256, i128, i128) {
         r0 = r1;
         r1 = r;
 
-        // Convert q to i128 (this is fine as long as q fits)
-        let q_i128 = q.as_u128() as i128;
-
-        let s = s0 - q_i128 * s1;
+        let s = s0.sub_mul(q, &s1);
         s0 = s1;
         s1 = s;
 
-        let t = t0 - q_i128 * t1;
+        let t = t0.sub_mul(q, &t1);
         t0 = t1;
         t1 = t;
     }
@@ -79,18 +197,56 @@ pub fn extended_gcd(a: U256, b: U256) -> (U256, i128, i128) {
 /// returns `Some(inverse)`, where `inverse` is in the range `[0, m - 1]`.
 /// Otherwise, returns `None`.
 pub fn mod_inverse(a: U256, m: U256) -> Option<U256> {
-    let (g, x, _) = extended_gcd(a, m);
+    let (g, x, _) = extended_gcd(a % m, m);
     // If gcd(a, m) != 1, then no modular inverse exists
     if g != U256::from(1u8) {
         return None;
     }
-    // Ensure the result is positive in modulo m space.
-    // Using (x mod m + m) mod m to handle negative x values.
-    // Note: x is i128 and m is U256. This conversion assumes m fits into i128.
-    let m_i128 = m.as_u128() as i128; // Valid if m fits in i128
-    let mut x_i128 = x % m_i128;
-    if x_i128 < 0 {
-        x_i128 += m_i128;
+    // Map the possibly negative coefficient into [0, m - 1].
+    Some(x.rem_euclid(m))
+}
+
+/// Solves the system of congruences `x ≡ aᵢ (mod mᵢ)` given as `(aᵢ, mᵢ)` pairs
+/// (Chinese Remainder Theorem).
+///
+/// The moduli need not be pairwise coprime: the system is solvable iff
+/// `aᵢ ≡ aⱼ (mod gcd(mᵢ, mⱼ))` for all pairs, and the solution is then unique
+/// modulo `lcm(m₀, …, mₙ₋₁)`. Congruences are merged one at a time into a
+/// 512-bit accumulator, so e.g. the two 256-bit primes of RSA-CRT fit.
+///
+/// Each modulus must fit into 256 bits, while the combined modulus may use
+/// up to 512; a `U512` result therefore cannot be passed back in as a
+/// congruence, and systems with a modulus wider than 256 bits are out of scope.
+///
+/// Returns `Some((x, m))` with `m = lcm(mᵢ)` and `0 <= x < m`, or `None` if the
+/// system is inconsistent, a modulus is zero, or `m` does not fit into `U512`.
+pub fn crt(congruences: &[(U256, U256)]) -> Option<(U512, U512)> {
+    let mut x = U512::zero();
+    let mut modulus = U512::one();
+
+    for &(a, m) in congruences {
+        if m.is_zero() {
+            return None;
+        }
+        // Everything needed to merge with x ≡ a (mod m) lives modulo m,
+        // so the 512-bit accumulator is reduced into 256 bits first.
+        let narrow = |v: U512| U256::try_from(v).expect("a value reduced modulo a U256 fits into U256");
+        let g = gcd(narrow(modulus % U512::from(m)), m);
+        let diff = sub_mod(a, narrow(x % U512::from(m)), m);
+        if !(diff % g).is_zero() {
+            return None;
+        }
+
+        // Solve (M/g)·k ≡ diff/g (mod m/g), where M/g and m/g are coprime
+        let m_reduced = m / g;
+        let step = mod_inverse(narrow((modulus / U512::from(g)) % U512::from(m_reduced)), m_reduced)
+            .expect("M/g and m/g are coprime");
+        let k = mul_mod(diff / g, step, m_reduced);
+
+        // x + M·k < M·(m/g) = lcm(M, m), so only the new modulus can overflow
+        let merged = modulus.checked_mul(U512::from(m_reduced))?;
+        x += modulus * U512::from(k);
+        modulus = merged;
     }
-    Some(U256::from(x_i128 as u128))
+    Some((x, modulus))
 }
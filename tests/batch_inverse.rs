@@ -0,0 +1,65 @@
+use crypto::algebra::{
+    field::{
+        fp_impls::{fp7_impl::Fp_7, fp_secp256k1::FP_SECP265K1},
+        operation_helpers::batch_inverse,
+    },
+    operations::{BinaryOperation, Multiplicative},
+    properties::Invertible,
+};
+use primitive_types::U256;
+
+fn fp7(values: &[u64]) -> Vec<U256> {
+    values.iter().map(|&v| U256::from(v)).collect()
+}
+
+fn some(values: &[Option<u64>]) -> Vec<Option<U256>> {
+    values.iter().map(|v| v.map(U256::from)).collect()
+}
+
+#[test]
+fn inverts_all_nonzero_elements() {
+    // 1·1 = 2·4 = 3·5 = 6·6 = 1 (mod 7)
+    assert_eq!(
+        batch_inverse::<Fp_7, U256>(&fp7(&[1, 2, 3, 4, 5, 6])),
+        some(&[Some(1), Some(4), Some(5), Some(2), Some(3), Some(6)])
+    );
+}
+
+#[test]
+fn reports_mixed_zeros() {
+    assert_eq!(
+        batch_inverse::<Fp_7, U256>(&fp7(&[0, 3, 0, 5, 0])),
+        some(&[None, Some(5), None, Some(3), None])
+    );
+}
+
+#[test]
+fn reports_non_canonical_zeros() {
+    assert_eq!(
+        batch_inverse::<Fp_7, U256>(&fp7(&[3, 7, 5])),
+        some(&[Some(5), None, Some(3)])
+    );
+    assert_eq!(
+        batch_inverse::<Fp_7, U256>(&fp7(&[14, 10])),
+        some(&[None, Some(5)])
+    );
+}
+
+#[test]
+fn all_zeros_and_empty_input() {
+    assert_eq!(batch_inverse::<Fp_7, U256>(&fp7(&[0, 7, 0])), vec![None; 3]);
+    assert_eq!(batch_inverse::<Fp_7, U256>(&[]), vec![]);
+}
+
+#[test]
+fn matches_individual_inverses_for_full_size_elements() {
+    let elements: Vec<U256> = (1..20u64)
+        .map(|i| U256::from(i).overflowing_pow(U256::from(40)).0)
+        .collect();
+    let inverses = batch_inverse::<FP_SECP265K1, U256>(&elements);
+    for (element, inverse) in elements.iter().zip(inverses) {
+        let inverse = inverse.unwrap();
+        assert_eq!(Some(inverse), <U256 as Invertible<Multiplicative, FP_SECP265K1>>::inverse(element));
+        assert_eq!(<U256 as BinaryOperation<Multiplicative, FP_SECP265K1>>::op(element, &inverse), U256::one());
+    }
+}
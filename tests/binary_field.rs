@@ -0,0 +1,72 @@
+use crypto::algebra::{
+    field::fp_impls::gf2n::{GF_2_128, GF_2_8},
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{Finite, Invertible},
+    AlgebraicStructure,
+};
+use primitive_types::U256;
+
+fn mul<F: AlgebraicStructure<Multiplicative, Element = u128>>(a: u128, b: u128) -> u128
+where
+    u128: BinaryOperation<Multiplicative, F>,
+{
+    <u128 as BinaryOperation<Multiplicative, F>>::op(&a, &b)
+}
+
+fn inv<F: AlgebraicStructure<Multiplicative, Element = u128>>(a: u128) -> Option<u128>
+where
+    u128: Invertible<Multiplicative, F>,
+{
+    <u128 as Invertible<Multiplicative, F>>::inverse(&a)
+}
+
+#[test]
+fn aes_multiplication_vectors() {
+    // FIPS-197: {57}·{83} = {c1}, {57}·{13} = {fe}, and {53} is the inverse of {ca}
+    assert_eq!(mul::<GF_2_8>(0x57, 0x83), 0xC1);
+    assert_eq!(mul::<GF_2_8>(0x57, 0x13), 0xFE);
+    assert_eq!(mul::<GF_2_8>(0x53, 0xCA), 0x01);
+    assert_eq!(inv::<GF_2_8>(0x53), Some(0xCA));
+}
+
+#[test]
+fn gf2_8_inverses() {
+    for a in 1..=0xFFu128 {
+        let a_inv = inv::<GF_2_8>(a).unwrap();
+        assert!(GF_2_8::contains(&a_inv));
+        assert_eq!(mul::<GF_2_8>(a, a_inv), 1);
+    }
+    assert_eq!(inv::<GF_2_8>(0), None);
+}
+
+#[test]
+fn gf2_128_reduction_and_inverses() {
+    // x · x¹²⁷ = x¹²⁸ ≡ x⁷ + x² + x + 1
+    assert_eq!(mul::<GF_2_128>(2, 1 << 127), 0x87);
+
+    for a in [1u128, 2, 0x87, 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210, u128::MAX] {
+        let a_inv = inv::<GF_2_128>(a).unwrap();
+        assert_eq!(mul::<GF_2_128>(a, a_inv), 1);
+        assert_eq!(mul::<GF_2_128>(a_inv, a), 1);
+    }
+    assert_eq!(inv::<GF_2_128>(0), None);
+}
+
+#[test]
+fn addition_is_xor() {
+    let (a, b) = (0x0123_4567_89AB_CDEFu128, 0xFEDC_BA98_7654_3210u128);
+    assert_eq!(<u128 as BinaryOperation<Additive, GF_2_128>>::op(&a, &b), a ^ b);
+    assert_eq!(<u128 as Invertible<Additive, GF_2_128>>::inverse(&a), Some(a));
+    // Multiplication distributes over XOR
+    let c = 0x1B;
+    assert_eq!(
+        mul::<GF_2_128>(a ^ b, c),
+        mul::<GF_2_128>(a, c) ^ mul::<GF_2_128>(b, c)
+    );
+}
+
+#[test]
+fn field_order() {
+    assert_eq!(GF_2_8::default().order(), U256::from(256));
+    assert_eq!(GF_2_128::default().order(), U256::one() << 128);
+}
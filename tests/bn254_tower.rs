@@ -0,0 +1,144 @@
+use crypto::algebra::{
+    field::{
+        extension::{
+            cubic::{CubicElement, CubicExtensionConfig},
+            quadratic::{QuadraticElement, QuadraticExtensionConfig},
+            Frobenius,
+        },
+        fp_impls::bn254::{
+            Bn254Fp12Config, Bn254Fp2Config, Bn254Fp6Config, Fp12Element, Fp2Element, Fp6Element,
+            FP12_BN254, FP2_BN254, FP6_BN254, FP_BN254, P,
+        },
+    },
+    group::extension::Power,
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{Identity, Invertible},
+};
+use primitive_types::U256;
+
+fn fp2(a: u64, b: u64) -> Fp2Element {
+    // Spread the coefficients over the full width of 𝔽ₚ
+    let spread = |x: u64| FP_BN254::pow(&U256::from(x), &U256::from(1_000_003u64));
+    QuadraticElement::new(spread(a), spread(b))
+}
+
+fn fp6(seed: u64) -> Fp6Element {
+    CubicElement::new(fp2(seed, seed + 1), fp2(seed + 2, seed + 3), fp2(seed + 4, seed + 5))
+}
+
+fn fp12(seed: u64) -> Fp12Element {
+    QuadraticElement::new(fp6(seed), fp6(seed + 6))
+}
+
+#[test]
+fn precomputed_frobenius_coefficients_match_definitions() {
+    let third = (P - 1) / 3;
+    assert_eq!(
+        Bn254Fp6Config::frobenius_coefficient(),
+        <FP2_BN254 as Power<Multiplicative, _>>::pow(&Bn254Fp6Config::non_residue(), third)
+    );
+    let half = (P - 1) >> 1;
+    assert_eq!(Bn254Fp2Config::frobenius_coefficient(), FP_BN254::pow(&Bn254Fp2Config::non_residue(), &half));
+    assert_eq!(
+        Bn254Fp12Config::frobenius_coefficient(),
+        <FP6_BN254 as Power<Multiplicative, _>>::pow(&Bn254Fp12Config::non_residue(), half)
+    );
+}
+
+#[test]
+fn frobenius_is_pth_power() {
+    let a = fp2(3, 5);
+    assert_eq!(FP2_BN254::frobenius_map(&a, 1), <FP2_BN254 as Power<Multiplicative, _>>::pow(&a, P));
+    let b = fp6(7);
+    assert_eq!(FP6_BN254::frobenius_map(&b, 1), <FP6_BN254 as Power<Multiplicative, _>>::pow(&b, P));
+    let c = fp12(11);
+    assert_eq!(FP12_BN254::frobenius_map(&c, 1), <FP12_BN254 as Power<Multiplicative, _>>::pow(&c, P));
+}
+
+#[test]
+fn frobenius_has_order_of_extension_degree() {
+    let a = fp2(3, 5);
+    assert_eq!(FP2_BN254::frobenius_map(&a, 2), a);
+    assert_ne!(FP2_BN254::frobenius_map(&a, 1), a);
+
+    let b = fp6(7);
+    assert_eq!(FP6_BN254::frobenius_map(&b, 6), b);
+    assert_ne!(FP6_BN254::frobenius_map(&b, 3), b);
+
+    let c = fp12(11);
+    assert_eq!(FP12_BN254::frobenius_map(&c, 12), c);
+    assert_ne!(FP12_BN254::frobenius_map(&c, 6), c);
+    // Frobenius is a homomorphism
+    let d = fp12(23);
+    assert_eq!(
+        FP12_BN254::frobenius_map(&<Fp12Element as BinaryOperation<Multiplicative, FP12_BN254>>::op(&c, &d), 2),
+        <Fp12Element as BinaryOperation<Multiplicative, FP12_BN254>>::op(
+            &FP12_BN254::frobenius_map(&c, 2),
+            &FP12_BN254::frobenius_map(&d, 2)
+        )
+    );
+}
+
+#[test]
+fn conjugate_is_frobenius_in_fp2() {
+    for (a, b) in [(3, 5), (0, 1), (17, 0)] {
+        let x = fp2(a, b);
+        assert_eq!(FP2_BN254::conjugate(&x), FP2_BN254::frobenius_map(&x, 1));
+    }
+}
+
+#[test]
+fn inverses_in_tower() {
+    let a = fp2(3, 5);
+    let inv = <Fp2Element as Invertible<Multiplicative, FP2_BN254>>::inverse(&a).unwrap();
+    assert_eq!(
+        <Fp2Element as BinaryOperation<Multiplicative, FP2_BN254>>::op(&a, &inv),
+        <FP2_BN254 as Identity<Multiplicative, _>>::identity()
+    );
+
+    let b = fp6(7);
+    let inv = <Fp6Element as Invertible<Multiplicative, FP6_BN254>>::inverse(&b).unwrap();
+    assert_eq!(
+        <Fp6Element as BinaryOperation<Multiplicative, FP6_BN254>>::op(&b, &inv),
+        <FP6_BN254 as Identity<Multiplicative, _>>::identity()
+    );
+
+    let c = fp12(11);
+    let inv = <Fp12Element as Invertible<Multiplicative, FP12_BN254>>::inverse(&c).unwrap();
+    assert_eq!(
+        <Fp12Element as BinaryOperation<Multiplicative, FP12_BN254>>::op(&c, &inv),
+        <FP12_BN254 as Identity<Multiplicative, _>>::identity()
+    );
+
+    let zero = <FP12_BN254 as Identity<Additive, _>>::identity();
+    assert_eq!(<Fp12Element as Invertible<Multiplicative, FP12_BN254>>::inverse(&zero), None);
+}
+
+#[test]
+fn generators_satisfy_defining_relations() {
+    let zero2 = QuadraticElement::new(U256::zero(), U256::zero());
+    let one2 = QuadraticElement::new(U256::one(), U256::zero());
+    let zero6 = CubicElement::new(zero2.clone(), zero2.clone(), zero2.clone());
+
+    // u² = −1 in 𝔽ₚ²
+    let u = QuadraticElement::new(U256::zero(), U256::one());
+    assert_eq!(
+        <Fp2Element as BinaryOperation<Multiplicative, FP2_BN254>>::op(&u, &u),
+        QuadraticElement::new(P - 1, U256::zero())
+    );
+
+    // v³ = ξ in 𝔽ₚ⁶
+    let v = CubicElement::new(zero2.clone(), one2.clone(), zero2.clone());
+    assert_eq!(
+        <FP6_BN254 as Power<Multiplicative, _>>::pow(&v, U256::from(3)),
+        CubicElement::new(Bn254Fp6Config::non_residue(), zero2.clone(), zero2.clone())
+    );
+
+    // w² = v in 𝔽ₚ¹²
+    let one6 = CubicElement::new(one2, zero2.clone(), zero2);
+    let w = QuadraticElement::new(zero6.clone(), one6);
+    assert_eq!(
+        <Fp12Element as BinaryOperation<Multiplicative, FP12_BN254>>::op(&w, &w),
+        QuadraticElement::new(v, zero6)
+    );
+}
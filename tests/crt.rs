@@ -0,0 +1,80 @@
+use crypto::{algebra::field::fp_impls::fp_secp256k1::P, helpers::crt};
+use primitive_types::{U256, U512};
+
+/// The secp256k1 group order, a second 256-bit prime next to the field prime `P`.
+const N: U256 = U256([0xBFD25E8CD0364141, 0xBAAEDCE6AF48A03B, 0xFFFFFFFFFFFFFFFE, 0xFFFFFFFFFFFFFFFF]);
+
+fn small(congruences: &[(u64, u64)]) -> Option<(U512, U512)> {
+    let congruences: Vec<(U256, U256)> =
+        congruences.iter().map(|&(a, m)| (U256::from(a), U256::from(m))).collect();
+    crt(&congruences)
+}
+
+fn pair(x: u64, m: u64) -> Option<(U512, U512)> {
+    Some((U512::from(x), U512::from(m)))
+}
+
+#[test]
+fn coprime_moduli() {
+    // The classic Sunzi example: x ≡ 2 (mod 3), x ≡ 3 (mod 5), x ≡ 2 (mod 7)
+    assert_eq!(small(&[(2, 3), (3, 5), (2, 7)]), pair(23, 105));
+    assert_eq!(small(&[(0, 4), (0, 9)]), pair(0, 36));
+    assert_eq!(small(&[(3, 4), (8, 9)]), pair(35, 36));
+    // Residues need not be reduced
+    assert_eq!(small(&[(17, 3), (13, 5)]), pair(8, 15));
+    // A single congruence, and the empty system
+    assert_eq!(small(&[(10, 7)]), pair(3, 7));
+    assert_eq!(small(&[]), pair(0, 1));
+}
+
+#[test]
+fn non_coprime_moduli() {
+    // gcd(6, 9) = 3 and 2 ≡ 8 (mod 3), so the solution is unique modulo lcm = 18
+    assert_eq!(small(&[(2, 6), (8, 9)]), pair(8, 18));
+    assert_eq!(small(&[(1, 4), (3, 6), (7, 10)]), pair(57, 60));
+    // Repeating a congruence changes nothing
+    assert_eq!(small(&[(5, 12), (5, 12)]), pair(5, 12));
+    // A modulus dividing another only checks consistency
+    assert_eq!(small(&[(11, 12), (2, 3)]), pair(11, 12));
+}
+
+#[test]
+fn inconsistent_systems() {
+    // 1 ≢ 2 (mod gcd(6, 9) = 3)
+    assert_eq!(small(&[(1, 6), (2, 9)]), None);
+    assert_eq!(small(&[(0, 2), (1, 4)]), None);
+    assert_eq!(small(&[(2, 3), (3, 5), (1, 15)]), None);
+}
+
+#[test]
+fn zero_modulus() {
+    assert_eq!(small(&[(0, 0)]), None);
+    assert_eq!(small(&[(2, 3), (1, 0)]), None);
+}
+
+#[test]
+fn rsa_style_prime_pair() {
+    // Recombine a 512-bit value from its residues modulo two 256-bit primes
+    let x = (P - 1).full_mul(N - 2);
+    let residue = |m: U256| U256::try_from(x % U512::from(m)).unwrap();
+    let (solution, modulus) = crt(&[(residue(P), P), (residue(N), N)]).unwrap();
+    assert_eq!(modulus, P.full_mul(N));
+    assert_eq!(solution, x);
+
+    // The order of the congruences does not matter
+    assert_eq!(crt(&[(residue(N), N), (residue(P), P)]), Some((solution, modulus)));
+}
+
+#[test]
+fn overflow_past_u512() {
+    // lcm(P, N, 3) > 2⁵¹²
+    let three = U256::from(3);
+    assert_eq!(crt(&[(U256::one(), P), (U256::one(), N), (U256::one(), three)]), None);
+    // A third modulus sharing a factor with the first two still fits: lcm stays P·N
+    assert_eq!(
+        crt(&[(U256::one(), P), (U256::one(), N), (U256::one(), P)]),
+        Some((U512::one(), P.full_mul(N)))
+    );
+    // Two full-width coprime moduli fit exactly
+    assert!(crt(&[(U256::zero(), U256::MAX), (U256::zero(), U256::MAX - 1)]).is_some());
+}
@@ -0,0 +1,142 @@
+#![allow(non_camel_case_types)]
+
+use crypto::algebra::{
+    field::fp_impls::fp7_impl::{Fp_7, GF_7_3},
+    polynomial::Polynomial,
+};
+use primitive_types::U256;
+
+crypto::prime_field!(Fp_13, Fp13Modulus, U256([13, 0, 0, 0]));
+
+fn poly13(coefficients: &[u64]) -> Polynomial<Fp_13> {
+    Polynomial::new(coefficients.iter().map(|&c| U256::from(c)).collect())
+}
+
+fn poly7(coefficients: &[u64]) -> Polynomial<Fp_7> {
+    Polynomial::new(coefficients.iter().map(|&c| U256::from(c)).collect())
+}
+
+fn product<F>(factors: &[(Polynomial<F>, usize)]) -> Polynomial<F>
+where
+    Polynomial<F>: std::ops::Mul<Output = Polynomial<F>> + Clone,
+    for<'a> &'a Polynomial<F>: std::ops::Mul<&'a Polynomial<F>, Output = Polynomial<F>>,
+{
+    let mut iter = factors.iter().flat_map(|(g, e)| std::iter::repeat_n(g, *e));
+    let first = iter.next().unwrap().clone();
+    iter.fold(first, |acc, g| &acc * g)
+}
+
+#[test]
+fn irreducibility() {
+    // x² + 1 is irreducible over 𝔽₇ (7 ≡ 3 mod 4) but not over 𝔽₁₃ (5² ≡ −1)
+    assert!(poly7(&[1, 0, 1]).is_irreducible());
+    assert!(!poly13(&[1, 0, 1]).is_irreducible());
+    // x³ − 3, the modulus of GF(7³)
+    assert!(GF_7_3::modulus_polynomial().is_irreducible());
+    assert!(poly7(&[4, 0, 0, 1]).is_irreducible());
+    // x⁴ + 1 splits over every prime field, here into two quadratics with no roots
+    assert!(!poly7(&[1, 0, 0, 0, 1]).is_irreducible());
+    // Linear polynomials are irreducible, constants are not
+    assert!(poly7(&[3, 2]).is_irreducible());
+    assert!(!poly7(&[3]).is_irreducible());
+    assert!(!Polynomial::<Fp_7>::zero().is_irreducible());
+    // Non-monic input is handled
+    assert!(poly7(&[3, 0, 3]).is_irreducible());
+}
+
+#[test]
+fn cube_roots_of_unity_over_f13() {
+    // 13 ≡ 1 (mod 3), so x³ − 1 = (x − 1)(x − 3)(x − 9)
+    let f = poly13(&[12, 0, 0, 1]);
+    assert_eq!(
+        f.factor(),
+        vec![(poly13(&[4, 1]), 1), (poly13(&[10, 1]), 1), (poly13(&[12, 1]), 1)]
+    );
+    assert_eq!(f.roots(), vec![U256::from(1), U256::from(3), U256::from(9)]);
+}
+
+#[test]
+fn square_free_factorization_with_multiplicities() {
+    // (x + 1)²·(x² + 2): −2 is not a square mod 13, so x² + 2 is irreducible
+    let linear = poly13(&[1, 1]);
+    let quadratic = poly13(&[2, 0, 1]);
+    let f = product(&[(linear.clone(), 2), (quadratic.clone(), 1)]);
+
+    let mut parts = f.square_free_factorization();
+    parts.sort_by_key(|(_, i)| *i);
+    assert_eq!(parts, vec![(quadratic.clone(), 1), (linear.clone(), 2)]);
+    assert_eq!(f.factor(), vec![(linear.clone(), 2), (quadratic.clone(), 1)]);
+    assert_eq!(f.roots(), vec![U256::from(12)]);
+
+    // The leading coefficient is dropped
+    let scaled = f.scale(&U256::from(5));
+    assert_eq!(scaled.factor(), f.factor());
+    assert_eq!(product(&scaled.factor()), f);
+}
+
+#[test]
+fn square_free_factorization_of_pth_powers() {
+    // x¹³ has a vanishing derivative and is the 13th power of x
+    let x13 = Polynomial::<Fp_13>::monomial(U256::one(), 13);
+    assert_eq!(x13.square_free_factorization(), vec![(poly13(&[0, 1]), 13)]);
+    assert_eq!(x13.factor(), vec![(poly13(&[0, 1]), 13)]);
+    assert_eq!(x13.roots(), vec![U256::zero()]);
+
+    // (x² + 2)¹³ = x²⁶ + 2
+    let mut coefficients = vec![0; 27];
+    coefficients[0] = 2;
+    coefficients[26] = 1;
+    assert_eq!(poly13(&coefficients).square_free_factorization(), vec![(poly13(&[2, 0, 1]), 13)]);
+
+    // (x + 1)·x¹³ mixes a p-th power with an ordinary factor
+    let f = &poly13(&[1, 1]) * &x13;
+    let mut parts = f.square_free_factorization();
+    parts.sort_by_key(|(_, i)| *i);
+    assert_eq!(parts, vec![(poly13(&[1, 1]), 1), (poly13(&[0, 1]), 13)]);
+    assert_eq!(product(&f.factor()), f);
+}
+
+#[test]
+fn distinct_and_equal_degree_factorization() {
+    // (x + 1)(x + 2)·(x² + 1)(x² + x + 3)·(x³ − 3) over 𝔽₇
+    let linears = product(&[(poly7(&[1, 1]), 1), (poly7(&[2, 1]), 1)]);
+    let quadratics = product(&[(poly7(&[1, 0, 1]), 1), (poly7(&[3, 1, 1]), 1)]);
+    let cubic = poly7(&[4, 0, 0, 1]);
+    let f = product(&[(linears.clone(), 1), (quadratics.clone(), 1), (cubic.clone(), 1)]);
+
+    assert_eq!(
+        f.distinct_degree_factorization(),
+        vec![(linears.clone(), 1), (quadratics.clone(), 2), (cubic.clone(), 3)]
+    );
+    assert_eq!(linears.equal_degree_factorization(1), vec![poly7(&[1, 1]), poly7(&[2, 1])]);
+    assert_eq!(quadratics.equal_degree_factorization(2), vec![poly7(&[1, 0, 1]), poly7(&[3, 1, 1])]);
+    assert_eq!(cubic.equal_degree_factorization(3), vec![cubic.clone()]);
+
+    let factors = f.factor();
+    assert_eq!(factors.len(), 5);
+    assert!(factors.iter().all(|(g, e)| *e == 1 && g.is_irreducible()));
+    assert_eq!(product(&factors), f);
+    assert_eq!(f.roots(), vec![U256::from(5), U256::from(6)]);
+}
+
+#[test]
+fn roots_of_polynomials_without_roots() {
+    assert!(poly13(&[2, 0, 1]).roots().is_empty());
+    assert!(poly13(&[5]).roots().is_empty());
+    assert!(Polynomial::<Fp_13>::zero().roots().is_empty());
+}
+
+#[test]
+fn pow_mod_matches_division() {
+    let modulus = poly13(&[2, 0, 1]);
+    let x = poly13(&[0, 1]);
+    for e in [0u64, 1, 2, 13, 27, 168] {
+        let direct = Polynomial::<Fp_13>::monomial(U256::one(), e as usize).div_rem(&modulus).unwrap().1;
+        assert_eq!(x.pow_mod(U256::from(e), &modulus), Some(direct));
+    }
+    // x² ≡ −2, so x^168 = (−2)^84 = 2^84 = 1 since the order of 2 mod 13 is 12
+    assert_eq!(x.pow_mod(U256::from(168), &modulus), Some(Polynomial::one()));
+    // Everything is zero modulo a constant
+    assert!(x.pow_mod(U256::from(5), &poly13(&[3])).unwrap().is_zero());
+    assert_eq!(x.pow_mod(U256::from(5), &Polynomial::zero()), None);
+}
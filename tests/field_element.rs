@@ -0,0 +1,87 @@
+use crypto::algebra::field::{
+    element::FieldElement,
+    fp_impls::{fp7_impl::Fp_7, fp_secp256k1::FP_SECP265K1},
+};
+use primitive_types::U256;
+
+type F7 = FieldElement<Fp_7>;
+type Secp = FieldElement<FP_SECP265K1>;
+
+fn f7(value: u64) -> F7 {
+    F7::from(value)
+}
+
+#[test]
+fn from_reduces_modulo_p() {
+    assert_eq!(f7(9), f7(2));
+    assert_eq!(*f7(7).value(), U256::zero());
+    assert_eq!(*F7::from(U256::MAX).value(), U256::MAX % 7);
+
+    let p = U256::from_str_radix("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F", 16).unwrap();
+    assert_eq!(Secp::from(p + 5), Secp::from(5u64));
+    assert!(Secp::from(p).is_zero());
+}
+
+#[test]
+#[allow(clippy::op_ref)] // the reference operator impls are exercised on purpose
+fn binary_operators() {
+    assert_eq!(f7(5) + f7(4), f7(2));
+    assert_eq!(f7(2) - f7(5), f7(4));
+    assert_eq!(f7(3) * f7(5), f7(1));
+    assert_eq!(f7(1) / f7(3), f7(5));
+    assert_eq!(f7(6) / f7(2), f7(3));
+
+    // Reference operands
+    let (a, b) = (f7(3), f7(4));
+    assert_eq!(&a + &b, f7(0));
+    assert_eq!(&a - &b, f7(6));
+    assert_eq!(&a * &b, f7(5));
+    assert_eq!(&a / &b, f7(6));
+}
+
+#[test]
+fn negation() {
+    assert_eq!(-f7(3), f7(4));
+    assert_eq!(-&f7(0), f7(0));
+    assert_eq!(f7(3) + -f7(3), F7::zero());
+}
+
+#[test]
+fn assign_operators() {
+    let mut x = f7(3);
+    x += f7(6);
+    assert_eq!(x, f7(2));
+    x -= f7(5);
+    assert_eq!(x, f7(4));
+    x *= f7(4);
+    assert_eq!(x, f7(2));
+    x /= f7(4);
+    assert_eq!(x, f7(4));
+}
+
+#[test]
+fn helpers() {
+    assert_eq!(F7::one(), f7(1));
+    assert!(F7::zero().is_zero());
+    assert_eq!(f7(3).square(), f7(2));
+    assert_eq!(f7(5).double(), f7(3));
+    assert_eq!(f7(3).pow(U256::from(6)), F7::one());
+    assert_eq!(f7(3).inverse(), Some(f7(5)));
+    assert_eq!(F7::zero().inverse(), None);
+
+    let x = Secp::from(0x1234_5678_9ABCu64);
+    assert_eq!(x * x.inverse().unwrap(), Secp::one());
+    assert_eq!((x + x) - x, x);
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn division_by_zero_panics() {
+    let _ = f7(3) / f7(0);
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn division_by_non_canonical_zero_panics() {
+    let _ = f7(3) / F7::new(U256::from(7));
+}
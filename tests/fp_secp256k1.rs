@@ -0,0 +1,115 @@
+use crypto::algebra::{
+    field::{
+        fp::FieldModulus,
+        fp_impls::fp_secp256k1::{reduce_wide, Secp256k1Modulus, FP_SECP265K1, P},
+        montgomery::montgomery_mul,
+    },
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::Invertible,
+};
+use primitive_types::{U256, U512};
+
+fn hex(s: &str) -> U256 {
+    U256::from_str_radix(s, 16).unwrap()
+}
+
+fn add(a: &U256, b: &U256) -> U256 {
+    <U256 as BinaryOperation<Additive, FP_SECP265K1>>::op(a, b)
+}
+
+fn mul(a: &U256, b: &U256) -> U256 {
+    <U256 as BinaryOperation<Multiplicative, FP_SECP265K1>>::op(a, b)
+}
+
+// Coordinates of the secp256k1 generator, used as realistic full-size operands.
+fn gx() -> U256 {
+    hex("79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798")
+}
+
+fn gy() -> U256 {
+    hex("483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8")
+}
+
+#[test]
+fn modulus_matches_secp256k1_prime() {
+    assert_eq!(
+        P,
+        hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F")
+    );
+}
+
+#[test]
+fn multiplication_of_full_size_operands() {
+    assert_eq!(
+        mul(&gx(), &gy()),
+        hex("FD3DC529C6EB60FB9D166034CF3C1A5A72324AA9DFD3428A56D7E1CE0179FD9B")
+    );
+    assert_eq!(
+        mul(&gx(), &gx()),
+        hex("8550E7D238FCF3086BA9ADCF0FB52A9DE3652194D06CB5BB38D50229B854FC49")
+    );
+    // (p - 1)² = (-1)² = 1
+    let minus_one = P - U256::one();
+    assert_eq!(mul(&minus_one, &minus_one), U256::one());
+}
+
+#[test]
+fn addition_carries_out_of_256_bits() {
+    assert_eq!(
+        add(&gx(), &gy()),
+        hex("C1F940F620808011B3455E91DC9813AFFFB3B123D4537CF2F63A51EB1208EC50")
+    );
+    // (p - 1) + (p - 2) overflows U256 before reduction.
+    let sum = add(&(P - U256::one()), &(P - U256::from(2)));
+    assert_eq!(sum, P - U256::from(3));
+}
+
+#[test]
+fn generator_satisfies_curve_equation() {
+    // y² = x³ + 7 over the secp256k1 base field.
+    let lhs = mul(&gy(), &gy());
+    let rhs = add(&mul(&mul(&gx(), &gx()), &gx()), &U256::from(7));
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+fn additive_inverse_of_full_size_operand() {
+    let neg = <U256 as Invertible<Additive, FP_SECP265K1>>::inverse(&gx()).unwrap();
+    assert_eq!(add(&gx(), &neg), U256::zero());
+}
+
+#[test]
+fn pseudo_mersenne_reduction_matches_division() {
+    let reference = |x: U512| U256::try_from(x % U512::from(P)).unwrap();
+    let wide = [
+        U512::zero(),
+        U512::from(P),
+        U512::from(P) - 1,
+        U512::one() << 256,
+        (U512::one() << 256) - 1,
+        (P - 1).full_mul(P - 1),
+        gx().full_mul(gy()),
+        // Unreduced operands: (2²⁵⁶ − 1)² and the largest 512-bit value,
+        // whose folds carry out of 256 bits
+        U256::MAX.full_mul(U256::MAX),
+        U512::MAX,
+    ];
+    for x in wide {
+        assert_eq!(reduce_wide(&x), reference(x));
+    }
+    // 2²⁵⁶ ≡ 2³² + 977
+    assert_eq!(reduce_wide(&(U512::one() << 256)), hex("1000003D1"));
+}
+
+#[test]
+fn specialized_mul_matches_montgomery_mul() {
+    let r2 = Secp256k1Modulus::R2;
+    let inv = Secp256k1Modulus::INV;
+    let operands = [U256::zero(), U256::one(), gx(), gy(), P - 1, P - 2, hex("1000003D1")];
+    for a in operands {
+        for b in operands {
+            let montgomery = montgomery_mul(&montgomery_mul(&a, &b, &P, inv), &r2, &P, inv);
+            assert_eq!(Secp256k1Modulus::mul(&a, &b), montgomery);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crypto::algebra::{
+    field::fp_impls::{bn254::FR_BN254, fp7_impl::Fp_7},
+    polynomial::Polynomial,
+};
+use primitive_types::U256;
+
+fn points(xs: &[u64], f: &Polynomial<Fp_7>) -> Vec<(U256, U256)> {
+    xs.iter().map(|&x| (U256::from(x), f.evaluate(&U256::from(x)))).collect()
+}
+
+#[test]
+fn interpolation_recovers_the_polynomial() {
+    // 2x³ + 5x + 1 through four points, and through all seven elements of 𝔽₇
+    let f = Polynomial::<Fp_7>::new([1u64, 5, 0, 2].map(U256::from).to_vec());
+    assert_eq!(Polynomial::<Fp_7>::interpolate(&points(&[0, 3, 4, 6], &f)), Some(f.clone()));
+    assert_eq!(Polynomial::<Fp_7>::interpolate(&points(&[0, 1, 2, 3, 4, 5, 6], &f)), Some(f));
+
+    // A single point gives a constant
+    let constant = Polynomial::<Fp_7>::interpolate(&[(U256::from(3), U256::from(5))]).unwrap();
+    assert_eq!(constant, Polynomial::constant(U256::from(5)));
+}
+
+#[test]
+fn interpolation_over_a_large_field() {
+    let ys = [
+        U256::from(17),
+        U256::from_dec_str("123456789012345678901234567890").unwrap(),
+        U256::zero(),
+        U256::one(),
+        U256::from(42),
+    ];
+    let pts: Vec<(U256, U256)> = ys.iter().enumerate().map(|(i, y)| (U256::from(i * i + 1), *y)).collect();
+    let f = Polynomial::<FR_BN254>::interpolate(&pts).unwrap();
+    assert!(f.degree() < Some(pts.len()));
+    let xs: Vec<U256> = pts.iter().map(|(x, _)| *x).collect();
+    assert_eq!(f.evaluate_many(&xs), ys.to_vec());
+}
+
+#[test]
+fn duplicate_x_is_rejected() {
+    let pts = [(U256::from(1), U256::from(2)), (U256::from(3), U256::from(4)), (U256::from(1), U256::from(5))];
+    assert_eq!(Polynomial::<Fp_7>::interpolate(&pts), None);
+    // Coordinates are compared modulo p: 8 ≡ 1
+    let pts = [(U256::from(1), U256::from(2)), (U256::from(8), U256::from(2))];
+    assert_eq!(Polynomial::<Fp_7>::interpolate(&pts), None);
+}
+
+#[test]
+fn empty_input() {
+    assert_eq!(Polynomial::<Fp_7>::interpolate(&[]), Some(Polynomial::zero()));
+    assert_eq!(Polynomial::<Fp_7>::vanishing(&[]), Polynomial::one());
+}
+
+#[test]
+fn vanishing_polynomial() {
+    let xs = [1u64, 2, 4].map(U256::from);
+    let z = Polynomial::<Fp_7>::vanishing(&xs);
+    // (x − 1)(x − 2)(x − 4) = x³ − 7x² + 14x − 8 ≡ x³ + 6
+    assert_eq!(z, Polynomial::new([6u64, 0, 0, 1].map(U256::from).to_vec()));
+    for x in 0..7u64 {
+        let value = z.evaluate(&U256::from(x));
+        assert_eq!(value.is_zero(), [1, 2, 4].contains(&x));
+    }
+}
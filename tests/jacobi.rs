@@ -0,0 +1,87 @@
+use crypto::{
+    algebra::field::fp_impls::{fp7_impl::Fp_7, fp_secp256k1::FP_SECP265K1},
+    helpers::jacobi,
+};
+use primitive_types::U256;
+
+fn j(a: u64, n: u64) -> i8 {
+    jacobi(U256::from(a), U256::from(n))
+}
+
+/// Legendre symbol by Euler's criterion, for small primes.
+fn euler(a: u64, p: u64) -> i8 {
+    let mut result = 1u64;
+    for _ in 0..(p - 1) / 2 {
+        result = result * (a % p) % p;
+    }
+    match result {
+        0 => 0,
+        1 => 1,
+        _ => -1,
+    }
+}
+
+#[test]
+fn known_values() {
+    assert_eq!(j(1, 3), 1);
+    assert_eq!(j(2, 3), -1);
+    assert_eq!(j(2, 7), 1);
+    assert_eq!(j(3, 7), -1);
+    assert_eq!(j(19, 45), 1);
+    assert_eq!(j(8, 21), -1);
+    assert_eq!(j(5, 21), 1);
+    assert_eq!(j(1001, 9907), -1);
+}
+
+#[test]
+fn composite_moduli() {
+    // (2 / 15) = (2 / 3)·(2 / 5) = (−1)·(−1) = 1, although 2 is not a square mod 15
+    assert_eq!(j(2, 15), 1);
+    assert_eq!(j(7, 15), -1);
+    // Not coprime to the modulus
+    assert_eq!(j(3, 15), 0);
+    assert_eq!(j(10, 15), 0);
+    assert_eq!(j(0, 15), 0);
+    // (a / 1) = 1 for every a
+    assert_eq!(j(0, 1), 1);
+    assert_eq!(j(5, 1), 1);
+
+    // Multiplicativity in the modulus: (a / 3·5·7) = (a / 3)(a / 5)(a / 7)
+    for a in 0..105 {
+        assert_eq!(j(a, 105), euler(a, 3) * euler(a, 5) * euler(a, 7));
+    }
+}
+
+#[test]
+fn matches_euler_criterion_for_primes() {
+    for p in [3u64, 5, 7, 11, 13, 17, 101] {
+        for a in 0..2 * p {
+            assert_eq!(j(a, p), euler(a, p), "({a} / {p})");
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "odd moduli")]
+fn rejects_even_modulus() {
+    j(3, 8);
+}
+
+#[test]
+fn legendre_on_prime_fields() {
+    assert_eq!(Fp_7::legendre(&U256::zero()), 0);
+    assert_eq!(Fp_7::legendre(&U256::from(7)), 0);
+    // Squares modulo 7 are 1, 2, 4
+    let residues: Vec<i8> = (1..7u64).map(|a| Fp_7::legendre(&U256::from(a))).collect();
+    assert_eq!(residues, vec![1, 1, -1, 1, -1, -1]);
+
+    assert!(Fp_7::is_quadratic_residue(&U256::zero()));
+    assert!(Fp_7::is_quadratic_residue(&U256::from(2)));
+    assert!(!Fp_7::is_quadratic_residue(&U256::from(3)));
+
+    // p ≡ 3 (mod 4), so −1 is a non-residue
+    let p = U256::from_str_radix("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F", 16).unwrap();
+    assert_eq!(FP_SECP265K1::legendre(&(p - 1)), -1);
+    assert_eq!(FP_SECP265K1::legendre(&U256::from(4)), 1);
+    assert_eq!(FP_SECP265K1::legendre(&U256::zero()), 0);
+}
@@ -0,0 +1,76 @@
+use crypto::{
+    algebra::{
+        field::fp_impls::fp_secp256k1::{FP_SECP265K1, P},
+        operations::{BinaryOperation, Multiplicative},
+        properties::Invertible,
+    },
+    helpers::{extended_gcd, mod_inverse, mul_mod},
+};
+use primitive_types::U256;
+
+fn hex(s: &str) -> U256 {
+    U256::from_str_radix(s, 16).unwrap()
+}
+
+// Order of the secp256k1 group, a second full-size prime modulus.
+fn n() -> U256 {
+    hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141")
+}
+
+fn gx() -> U256 {
+    hex("79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798")
+}
+
+#[test]
+fn known_inverses_modulo_secp256k1_prime() {
+    assert_eq!(
+        mod_inverse(U256::from(2), P),
+        Some(hex("7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF7FFFFE18"))
+    );
+    assert_eq!(
+        mod_inverse(gx(), P),
+        Some(hex("237AFDF1D2938D86870AAEB8AD77626A67B8E794ABFB076BE61D003687CA9EF6"))
+    );
+    // -1 is its own inverse.
+    assert_eq!(mod_inverse(P - U256::one(), P), Some(P - U256::one()));
+}
+
+#[test]
+fn known_inverses_modulo_secp256k1_order() {
+    assert_eq!(
+        mod_inverse(U256::from(3), n()),
+        Some(hex("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA9D1C9E899CA306AD27FE1945DE0242B81"))
+    );
+    assert_eq!(
+        mod_inverse(gx(), n()),
+        Some(hex("1DD887B3EAF153260A95E8B9FD31F60AC115D26CCBE1F572C0B8D7A6DEC520FE"))
+    );
+}
+
+#[test]
+fn field_inverse_multiplies_to_one() {
+    let inv = <U256 as Invertible<Multiplicative, FP_SECP265K1>>::inverse(&gx()).unwrap();
+    assert_eq!(
+        <U256 as BinaryOperation<Multiplicative, FP_SECP265K1>>::op(&gx(), &inv),
+        U256::one()
+    );
+    assert_eq!(<U256 as Invertible<Multiplicative, FP_SECP265K1>>::inverse(&U256::zero()), None);
+}
+
+#[test]
+fn non_coprime_values_have_no_inverse() {
+    assert_eq!(mod_inverse(U256::from(6), U256::from(9)), None);
+}
+
+#[test]
+fn bezout_identity_holds_for_full_size_inputs() {
+    let (g, x, y) = extended_gcd(gx(), P);
+    assert_eq!(g, U256::one());
+    // gx·x + P·y = 1, checked modulo P and modulo gx.
+    assert_eq!(
+        <U256 as BinaryOperation<Multiplicative, FP_SECP265K1>>::op(&gx(), &x.rem_euclid(P)),
+        U256::one()
+    );
+    assert_eq!(mul_mod(P % gx(), y.rem_euclid(gx()), gx()), U256::one());
+    assert!(x.magnitude <= P && y.magnitude <= gx());
+}
@@ -0,0 +1,135 @@
+use crypto::{
+    algebra::field::{
+        fp::{FieldModulus, Fp, Montgomery},
+        fp_impls::{
+            bn254::{Bn254Modulus, P as BN254_P},
+            fp7_impl::Fp7Modulus,
+            fp_secp256k1::{Secp256k1Modulus, P as SECP256K1_P},
+        },
+        montgomery::{montgomery_mul, neg_inv, r, r2},
+    },
+    helpers::mul_mod,
+};
+use primitive_types::U256;
+
+fn hex(s: &str) -> U256 {
+    U256::from_str_radix(s, 16).unwrap()
+}
+
+// Full-size operands shared by the comparisons below.
+fn operands() -> Vec<U256> {
+    vec![
+        U256::zero(),
+        U256::one(),
+        U256::from(2),
+        hex("79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"),
+        hex("483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8"),
+        hex("0E0A77C19A07DF2F666EA36F7879462C0A78EB28F5C70B3DD35D438DC58F0D9D"),
+    ]
+}
+
+#[test]
+fn secp256k1_constants() {
+    // 2²⁵⁶ ≡ 2³² + 977 (mod p), and R² is its square
+    assert_eq!(U256(r(&SECP256K1_P.0)), hex("1000003D1"));
+    assert_eq!(U256(r2(&SECP256K1_P.0)), hex("1000007A2000E90A1"));
+    assert_eq!(neg_inv(SECP256K1_P.0[0]), 0xD838091DD2253531);
+    assert_eq!(Secp256k1Modulus::R, hex("1000003D1"));
+}
+
+#[test]
+fn bn254_constants() {
+    assert_eq!(
+        Bn254Modulus::R,
+        hex("0E0A77C19A07DF2F666EA36F7879462C0A78EB28F5C70B3DD35D438DC58F0D9D")
+    );
+    assert_eq!(
+        Bn254Modulus::R2,
+        hex("06D89F71CAB8351F47AB1EFF0A417FF6B5E71911D44501FBF32CFC5B538AFA89")
+    );
+    assert_eq!(Bn254Modulus::INV, 0x87D20782E4866389);
+    // INV · p ≡ −1 (mod 2⁶⁴)
+    assert_eq!(Bn254Modulus::INV.wrapping_mul(BN254_P.0[0]), u64::MAX);
+}
+
+#[test]
+fn small_modulus_constants() {
+    // 2²⁵⁶ mod 7 = 2 (the powers of 2 cycle with period 3), 2⁵¹² mod 7 = 4
+    assert_eq!(Fp7Modulus::R, U256::from(2));
+    assert_eq!(Fp7Modulus::R2, U256::from(4));
+    assert_eq!(Fp7Modulus::INV.wrapping_mul(7), u64::MAX);
+}
+
+#[test]
+fn montgomery_mul_matches_mul_mod() {
+    for p in [SECP256K1_P, BN254_P] {
+        let inv = neg_inv(p.0[0]);
+        let r2 = U256(r2(&p.0));
+        for a in operands() {
+            for b in operands() {
+                let (a, b) = (a % p, b % p);
+                // (a·b·R⁻¹)·R²·R⁻¹ = a·b
+                let product = montgomery_mul(&montgomery_mul(&a, &b, &p, inv), &r2, &p, inv);
+                assert_eq!(product, mul_mod(a, b, p));
+            }
+        }
+    }
+}
+
+#[test]
+fn default_field_mul_matches_mul_mod() {
+    for a in operands() {
+        for b in operands() {
+            let (a, b) = (a % BN254_P, b % BN254_P);
+            assert_eq!(Bn254Modulus::mul(&a, &b), mul_mod(a, b, BN254_P));
+        }
+    }
+}
+
+#[test]
+fn conversion_round_trip() {
+    for a in operands() {
+        let a = a % BN254_P;
+        let m = Montgomery::<Bn254Modulus>::new(&a);
+        assert_eq!(m.repr(), mul_mod(a, Bn254Modulus::R, BN254_P));
+        assert_eq!(m.value(), a);
+    }
+    assert_eq!(Montgomery::<Fp7Modulus>::new(&U256::from(9)).value(), U256::from(2));
+}
+
+#[test]
+fn arithmetic_in_montgomery_form() {
+    type M = Montgomery<Bn254Modulus>;
+    let values = operands();
+    for a in &values {
+        for b in &values {
+            let (a, b) = (a % BN254_P, b % BN254_P);
+            let (ma, mb) = (M::new(&a), M::new(&b));
+            assert_eq!((ma * mb).value(), mul_mod(a, b, BN254_P));
+            assert_eq!((ma + mb - mb).value(), a);
+            assert_eq!((ma + -ma), M::zero());
+        }
+        let a = M::new(a);
+        match a.inverse() {
+            Some(inv) => assert_eq!(a * inv, M::one()),
+            None => assert!(a.is_zero()),
+        }
+    }
+}
+
+#[test]
+fn pow_stays_in_montgomery_form() {
+    let base = operands()[3] % BN254_P;
+    // Fermat: a^(p−1) = 1 and a^p = a
+    assert_eq!(Fp::<Bn254Modulus>::pow(&base, &(BN254_P - 1)), U256::one());
+    assert_eq!(
+        Montgomery::<Bn254Modulus>::new(&base).pow(&BN254_P),
+        Montgomery::<Bn254Modulus>::new(&base)
+    );
+
+    let mut expected = U256::one();
+    for e in 0..20u64 {
+        assert_eq!(Fp::<Bn254Modulus>::pow(&base, &U256::from(e)), expected);
+        expected = mul_mod(expected, base, BN254_P);
+    }
+}
@@ -0,0 +1,115 @@
+use crypto::algebra::{
+    field::{
+        fp::{FieldModulus, Fp},
+        fp_impls::{bn254::Bn254ScalarModulus, goldilocks::GoldilocksModulus},
+        ntt::{coset_intt, coset_ntt, intt, ntt},
+        TwoAdicField,
+    },
+    polynomial::Polynomial,
+};
+use primitive_types::U256;
+
+// Deterministic pseudo-random field elements (a 64-bit LCG, reduced mod p)
+fn values<M: FieldModulus>(n: usize, seed: u64) -> Vec<U256> {
+    let mut state = seed;
+    (0..n)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (U256::from(state) << 192 | U256::from(state.rotate_left(17)) << 64 | U256::from(state)) % M::MODULUS
+        })
+        .collect()
+}
+
+fn check_root_orders<M: FieldModulus>()
+where
+    Fp<M>: TwoAdicField<U256>,
+{
+    let s = <Fp<M> as TwoAdicField<U256>>::TWO_ADICITY;
+    for k in 0..=s {
+        let root = Fp::<M>::root_of_unity(k).unwrap();
+        // ω^(2ᵏ) = 1, and for k > 0 the order is not smaller: ω^(2ᵏ⁻¹) = −1
+        assert_eq!(Fp::<M>::pow(&root, &(U256::one() << k)), U256::one());
+        if k > 0 {
+            assert_eq!(Fp::<M>::pow(&root, &(U256::one() << (k - 1))), M::MODULUS - 1);
+        }
+    }
+    assert_eq!(Fp::<M>::root_of_unity(s + 1), None);
+}
+
+fn check_round_trips<M: FieldModulus>()
+where
+    Fp<M>: TwoAdicField<U256>,
+{
+    for log_n in 0..=6 {
+        let n = 1 << log_n;
+        let coefficients = values::<M>(n, log_n as u64 + 1);
+        let polynomial = Polynomial::<Fp<M>>::new(coefficients.clone());
+
+        // The forward transform evaluates at the powers of ω
+        let mut evaluations = coefficients.clone();
+        ntt::<Fp<M>, U256>(&mut evaluations);
+        let root = Fp::<M>::root_of_unity(log_n).unwrap();
+        for (i, value) in evaluations.iter().enumerate() {
+            assert_eq!(*value, polynomial.evaluate(&Fp::<M>::pow(&root, &U256::from(i))));
+        }
+        intt::<Fp<M>, U256>(&mut evaluations);
+        assert_eq!(evaluations, coefficients);
+
+        // The coset transform evaluates at shift·ωⁱ
+        let shift = Fp::<M>::multiplicative_generator();
+        let mut evaluations = coefficients.clone();
+        coset_ntt::<Fp<M>, U256>(&mut evaluations, &shift);
+        for (i, value) in evaluations.iter().enumerate().take(4) {
+            let x = Fp::<M>::pow(&root, &U256::from(i));
+            assert_eq!(*value, polynomial.evaluate(&M::mul(&shift, &x)));
+        }
+        coset_intt::<Fp<M>, U256>(&mut evaluations, &shift);
+        assert_eq!(evaluations, coefficients);
+    }
+}
+
+fn check_mul_ntt<M: FieldModulus>()
+where
+    Fp<M>: TwoAdicField<U256>,
+{
+    for (len_a, len_b) in [(1, 1), (1, 7), (3, 5), (8, 8), (17, 30)] {
+        let a = Polynomial::<Fp<M>>::new(values::<M>(len_a, len_a as u64));
+        let b = Polynomial::<Fp<M>>::new(values::<M>(len_b, 100 + len_b as u64));
+        assert_eq!(a.mul_ntt(&b), &a * &b);
+    }
+    let a = Polynomial::<Fp<M>>::new(values::<M>(4, 9));
+    assert!(a.mul_ntt(&Polynomial::zero()).is_zero());
+}
+
+#[test]
+fn goldilocks_roots_of_unity() {
+    check_root_orders::<GoldilocksModulus>();
+}
+
+#[test]
+fn bn254_scalar_roots_of_unity() {
+    check_root_orders::<Bn254ScalarModulus>();
+}
+
+#[test]
+fn goldilocks_transform_round_trips() {
+    check_round_trips::<GoldilocksModulus>();
+}
+
+#[test]
+fn bn254_scalar_transform_round_trips() {
+    check_round_trips::<Bn254ScalarModulus>();
+}
+
+#[test]
+fn mul_ntt_matches_schoolbook() {
+    check_mul_ntt::<GoldilocksModulus>();
+    check_mul_ntt::<Bn254ScalarModulus>();
+}
+
+#[test]
+#[should_panic(expected = "NTT length must be a power of two")]
+fn rejects_non_power_of_two_length() {
+    let mut values = vec![U256::one(); 6];
+    ntt::<Fp<GoldilocksModulus>, U256>(&mut values);
+}
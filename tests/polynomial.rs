@@ -0,0 +1,115 @@
+use crypto::algebra::{field::fp_impls::fp7_impl::Fp_7, polynomial::Polynomial};
+use primitive_types::U256;
+
+type Poly = Polynomial<Fp_7>;
+
+fn poly(coefficients: &[u64]) -> Poly {
+    Poly::new(coefficients.iter().map(|&c| U256::from(c)).collect())
+}
+
+#[test]
+fn normalization() {
+    assert_eq!(poly(&[1, 2, 0, 0]), poly(&[1, 2]));
+    assert_eq!(poly(&[1, 2, 0]).degree(), Some(1));
+    assert!(poly(&[0, 0]).is_zero());
+    assert_eq!(Poly::zero().degree(), None);
+    assert_eq!(Poly::monomial(U256::from(3), 2), poly(&[0, 0, 3]));
+}
+
+#[test]
+fn non_canonical_coefficients() {
+    // 7 ≡ 0 and 10 ≡ 3 (mod 7), so 1 + 7x is the constant 1 and 10 + 8x is 3 + x
+    assert_eq!(poly(&[1, 7]), Poly::one());
+    assert_eq!(poly(&[1, 7]).degree(), Some(0));
+    assert_eq!(poly(&[10, 8]), poly(&[3, 1]));
+    assert!(poly(&[7, 14]).is_zero());
+    assert_eq!(poly(&[3, 1]).gcd(&poly(&[1, 7])), Poly::one());
+    assert_eq!(poly(&[3, 1]).gcd(&poly(&[10, 8])), poly(&[3, 1]));
+}
+
+#[test]
+fn div_rem_reconstructs_the_dividend() {
+    let a = poly(&[3, 0, 5, 1, 6, 2]);
+    for b in [poly(&[1, 1]), poly(&[2, 0, 3]), poly(&[4]), poly(&[6, 5, 4, 3, 2, 1])] {
+        let (q, r) = a.div_rem(&b).unwrap();
+        assert_eq!(&(&q * &b) + &r, a);
+        assert!(r.degree() < b.degree() || r.is_zero());
+    }
+
+    // A dividend of lower degree is its own remainder
+    let (q, r) = poly(&[1, 2]).div_rem(&poly(&[0, 0, 1])).unwrap();
+    assert!(q.is_zero());
+    assert_eq!(r, poly(&[1, 2]));
+
+    assert_eq!(a.div_rem(&Poly::zero()), None);
+}
+
+#[test]
+fn gcd_is_monic() {
+    // (x − 1)(x − 2) and 3·(x − 1)(x − 3) share the factor x − 1
+    let a = poly(&[2, 4, 1]);
+    let b = poly(&[3, 3, 1]).scale(&U256::from(3));
+    assert_eq!(a.gcd(&b), poly(&[6, 1]));
+    assert_eq!(b.gcd(&a), poly(&[6, 1]));
+
+    // gcd(f, 0) is f made monic, and coprime polynomials have gcd 1
+    assert_eq!(b.gcd(&Poly::zero()), poly(&[3, 3, 1]));
+    assert_eq!(poly(&[1, 1]).gcd(&poly(&[2, 1])), Poly::one());
+    assert!(Poly::zero().gcd(&Poly::zero()).is_zero());
+}
+
+#[test]
+fn derivative_in_characteristic_seven() {
+    // d/dx (x⁸ + 3x² + 5) = 8x⁷ + 6x = x⁷ + 6x
+    assert_eq!(poly(&[5, 0, 3, 0, 0, 0, 0, 0, 1]).derivative(), poly(&[0, 6, 0, 0, 0, 0, 0, 1]));
+    // x⁷ has derivative 7x⁶ = 0
+    assert!(Poly::monomial(U256::one(), 7).derivative().is_zero());
+    assert!(poly(&[4]).derivative().is_zero());
+}
+
+#[test]
+fn composition() {
+    let f = poly(&[1, 0, 1]); // x² + 1
+    let g = poly(&[1, 1]); // x + 1
+    assert_eq!(f.compose(&g), poly(&[2, 2, 1]));
+    assert_eq!(g.compose(&f), poly(&[2, 0, 1]));
+    assert_eq!(f.compose(&poly(&[0, 1])), f);
+    // Composition agrees with evaluation
+    for x in 0..7u64 {
+        let x = U256::from(x);
+        assert_eq!(f.compose(&g).evaluate(&x), f.evaluate(&g.evaluate(&x)));
+    }
+}
+
+#[test]
+fn arithmetic_operators() {
+    let f = poly(&[3, 0, 5, 1]);
+    let g = poly(&[6, 2, 2]);
+
+    assert!((&f + &-&f).is_zero());
+    assert_eq!(-f.clone(), poly(&[4, 0, 2, 6]));
+    assert_eq!(&(&f - &g) + &g, f);
+    assert_eq!(&f - &f, Poly::zero());
+    assert_eq!(f.clone() - g.clone(), poly(&[4, 5, 3, 1]));
+
+    let (q, r) = f.div_rem(&g).unwrap();
+    assert_eq!(&f / &g, q);
+    assert_eq!(&f % &g, r);
+
+    let mut h = f.clone();
+    h -= g.clone();
+    h += g.clone();
+    assert_eq!(h, f);
+    h *= g.clone();
+    h %= g.clone();
+    assert!(h.is_zero());
+    let mut h = &f * &g;
+    h /= g.clone();
+    assert_eq!(h, f);
+}
+
+#[test]
+#[should_panic(expected = "division by the zero polynomial")]
+fn rem_by_zero_panics() {
+    let _ = poly(&[1, 2]) % Poly::zero();
+}
@@ -0,0 +1,142 @@
+use crypto::{
+    algebra::{
+        field::fp_impls::{bn254::FP_BN254, fp7_impl::Fp_7, fp_secp256k1::FP_SECP265K1},
+        group::{
+            element::{ElementFinite, GroupOps},
+            extension::Power,
+            CyclingGroup, FiniteGroup, Group,
+        },
+        operations::{Additive, BinaryOperation, Multiplicative},
+        properties::{Associative, Commutative, Finite, Identity, Invertible},
+        AlgebraicStructure,
+    },
+    helpers::mul_mod,
+};
+use primitive_types::U256;
+
+fn hex(s: &str) -> U256 {
+    U256::from_str_radix(s, 16).unwrap()
+}
+
+fn exponents() -> Vec<U256> {
+    vec![
+        U256::zero(),
+        U256::one(),
+        U256::from(2),
+        U256::from(6),
+        U256::from(65537),
+        hex("AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522"),
+        U256::MAX,
+    ]
+}
+
+#[test]
+fn pow_matches_field_pow() {
+    let base = hex("79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798");
+    for exp in exponents() {
+        let expected = FP_SECP265K1::pow(&base, &exp);
+        assert_eq!(<FP_SECP265K1 as Power<Multiplicative, _>>::pow(&base, exp), expected);
+        assert_eq!(<FP_SECP265K1 as Power<Multiplicative, _>>::pow_ct(&base, exp), expected);
+
+        let base = base % hex("30644E72E131A029B85045B68181585D97816A916871CA8D3C208C16D87CFD47");
+        let expected = FP_BN254::pow(&base, &exp);
+        assert_eq!(<FP_BN254 as Power<Multiplicative, _>>::pow(&base, exp), expected);
+        assert_eq!(<FP_BN254 as Power<Multiplicative, _>>::pow_ct(&base, exp), expected);
+    }
+}
+
+#[test]
+fn pow_in_small_field() {
+    for base in 0..7u64 {
+        let mut expected = U256::one();
+        for exp in 0..20u64 {
+            let (base, exp) = (U256::from(base), U256::from(exp));
+            assert_eq!(<Fp_7 as Power<Multiplicative, _>>::pow(&base, exp), expected);
+            assert_eq!(<Fp_7 as Power<Multiplicative, _>>::pow_ct(&base, exp), expected);
+            expected = mul_mod(expected, base, U256::from(7));
+        }
+    }
+}
+
+#[test]
+fn additive_pow_is_scalar_multiple() {
+    let p = U256::from(7);
+    for a in 0..7u64 {
+        for k in [0u64, 1, 5, 13, 1000] {
+            let expected = mul_mod(U256::from(a), U256::from(k), p);
+            let a = U256::from(a);
+            assert_eq!(<Fp_7 as Power<Additive, _>>::pow(&a, U256::from(k)), expected);
+            assert_eq!(<Fp_7 as Power<Additive, _>>::pow_ct(&a, U256::from(k)), expected);
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// A small cyclic group to enumerate: ℤ/12ℤ under addition, generated by 1
+// -----------------------------------------------------------------------------
+#[derive(Debug)]
+struct Z12;
+
+const ORDER: u64 = 12;
+
+impl AlgebraicStructure<Additive> for Z12 {
+    type Element = U256;
+}
+
+impl BinaryOperation<Additive, Z12> for U256 {
+    fn op(a: &U256, b: &U256) -> U256 {
+        (a + b) % ORDER
+    }
+}
+
+impl Invertible<Additive, Z12> for U256 {
+    fn inverse(a: &U256) -> Option<U256> {
+        Some((U256::from(ORDER) - a) % ORDER)
+    }
+}
+
+impl Identity<Additive, Z12> for Z12 {
+    fn identity() -> U256 {
+        U256::zero()
+    }
+}
+
+impl Associative<Additive, Z12> for U256 {}
+impl Commutative<Additive, Z12> for U256 {}
+impl GroupOps<Additive, Z12> for U256 {}
+impl Group<Additive> for Z12 {}
+
+impl Finite for Z12 {
+    fn order(&self) -> U256 {
+        U256::from(ORDER)
+    }
+}
+
+impl ElementFinite<Additive, Z12> for U256 {
+    /// The order of k is 12 / gcd(k, 12).
+    fn element_order(_g: &Z12, element: &U256) -> U256 {
+        let mut n = 1;
+        while !(element.low_u64() * n).is_multiple_of(ORDER) {
+            n += 1;
+        }
+        U256::from(n)
+    }
+}
+
+impl FiniteGroup<Additive> for Z12 {}
+
+impl CyclingGroup<Additive> for Z12 {
+    fn generator(&self) -> U256 {
+        U256::one()
+    }
+}
+
+#[test]
+fn all_generators_of_small_cyclic_group() {
+    let mut generators: Vec<u64> = Z12.all_generators().iter().map(|g| g.low_u64()).collect();
+    generators.sort();
+    assert_eq!(generators, vec![1, 5, 7, 11]);
+    for g in 0..ORDER {
+        assert_eq!(Z12.is_generator(&U256::from(g)), generators.contains(&g));
+    }
+}
@@ -0,0 +1,92 @@
+use crypto::algebra::{
+    field::{extension::Frobenius, fp_impls::fp7_impl::GF_7_3},
+    group::extension::Power,
+    operations::{Additive, BinaryOperation, Multiplicative},
+    properties::{Finite, Invertible},
+};
+use primitive_types::U256;
+
+type Element = [U256; 3];
+
+fn element(c: [u64; 3]) -> Element {
+    GF_7_3::from_coefficients(c.map(U256::from))
+}
+
+fn mul(a: &Element, b: &Element) -> Element {
+    <Element as BinaryOperation<Multiplicative, GF_7_3>>::op(a, b)
+}
+
+// All 343 elements of GF(7³)
+fn elements() -> impl Iterator<Item = Element> {
+    (0..343u64).map(|n| element([n % 7, n / 7 % 7, n / 49]))
+}
+
+#[test]
+fn order_is_343() {
+    assert_eq!(GF_7_3::default().order(), U256::from(343));
+}
+
+#[test]
+fn every_nonzero_element_is_invertible() {
+    let one = element([1, 0, 0]);
+    for a in elements().skip(1) {
+        let a_inv = <Element as Invertible<Multiplicative, GF_7_3>>::inverse(&a).unwrap();
+        assert_eq!(mul(&a, &a_inv), one);
+    }
+    assert_eq!(<Element as Invertible<Multiplicative, GF_7_3>>::inverse(&element([0, 0, 0])), None);
+}
+
+#[test]
+fn reduction_and_addition() {
+    // x · x² = x³ ≡ 3
+    assert_eq!(mul(&element([0, 1, 0]), &element([0, 0, 1])), element([3, 0, 0]));
+    assert_eq!(
+        <Element as BinaryOperation<Additive, GF_7_3>>::op(&element([6, 5, 4]), &element([2, 3, 4])),
+        element([1, 1, 1])
+    );
+    let a = element([1, 2, 3]);
+    let neg = <Element as Invertible<Additive, GF_7_3>>::inverse(&a).unwrap();
+    assert_eq!(<Element as BinaryOperation<Additive, GF_7_3>>::op(&a, &neg), element([0, 0, 0]));
+}
+
+#[test]
+fn modulus_polynomial_is_irreducible() {
+    assert!(GF_7_3::modulus_polynomial().is_irreducible());
+}
+
+#[test]
+fn frobenius_and_norm() {
+    let a = element([2, 5, 1]);
+    let b = element([4, 0, 6]);
+    // The Frobenius map has order 3 and fixes exactly 𝔽₇
+    assert_eq!(GF_7_3::frobenius_map(&a, 3), a);
+    assert_ne!(GF_7_3::frobenius_map(&a, 1), a);
+    assert_eq!(GF_7_3::frobenius_map(&element([5, 0, 0]), 1), element([5, 0, 0]));
+
+    // The norm lands in 𝔽₇ and is multiplicative
+    let (na, nb) = (GF_7_3::norm(&a), GF_7_3::norm(&b));
+    assert!(na < U256::from(7) && !na.is_zero());
+    assert_eq!(GF_7_3::norm(&mul(&a, &b)), (na * nb) % 7);
+    // N(c) = c³ for c in 𝔽₇
+    assert_eq!(GF_7_3::norm(&element([3, 0, 0])), U256::from(27 % 7));
+}
+
+#[test]
+fn multiplicative_group_is_cyclic_of_order_342() {
+    let one = element([1, 0, 0]);
+    let pow = |a: &Element, exp: u64| <GF_7_3 as Power<Multiplicative, GF_7_3>>::pow(a, U256::from(exp));
+    // 342 = 2·3²·19, so a has order 342 iff a^(342/q) ≠ 1 for q = 2, 3, 19
+    let is_generator = |a: &Element| [2, 3, 19].iter().all(|q| pow(a, 342 / q) != one);
+
+    for a in elements().skip(1) {
+        assert_eq!(pow(&a, 342), one);
+        // The Frobenius map is exponentiation by p = 7
+        assert_eq!(GF_7_3::frobenius_map(&a, 1), pow(&a, 7));
+    }
+    // 1 + 3x generates the group, while x only has order 18 (x³ = 3 has order 6)
+    assert!(is_generator(&element([1, 3, 0])));
+    assert_eq!(pow(&element([0, 1, 0]), 18), one);
+    assert!(!is_generator(&element([0, 1, 0])));
+    // A cyclic group of order 342 has φ(342) = 108 generators
+    assert_eq!(elements().skip(1).filter(|a| is_generator(a)).count(), 108);
+}
@@ -0,0 +1,72 @@
+use crypto::{
+    algebra::{
+        field::{
+            fp_impls::{fp7_impl::Fp_7, fp_secp256k1::FP_SECP265K1},
+            safegcd::inverse,
+        },
+        operations::Multiplicative,
+        properties::Invertible,
+    },
+    helpers::mul_mod,
+};
+use primitive_types::U256;
+
+fn hex(s: &str) -> U256 {
+    U256::from_str_radix(s, 16).unwrap()
+}
+
+fn bn254_p() -> U256 {
+    hex("30644E72E131A029B85045B68181585D97816A916871CA8D3C208C16D87CFD47")
+}
+
+fn secp256k1_p() -> U256 {
+    hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F")
+}
+
+#[test]
+fn inverts_every_element_of_small_field() {
+    let p = U256::from(7);
+    for a in 1..7u64 {
+        let inv = inverse(&U256::from(a), &p);
+        assert!(inv < p);
+        assert_eq!(mul_mod(U256::from(a), inv, p), U256::one());
+    }
+}
+
+#[test]
+fn inverts_edge_values_of_large_fields() {
+    for p in [bn254_p(), secp256k1_p()] {
+        assert_eq!(inverse(&U256::one(), &p), U256::one());
+        // −1 is its own inverse
+        assert_eq!(inverse(&(p - 1), &p), p - 1);
+        // 2⁻¹ = (p + 1) / 2
+        assert_eq!(inverse(&U256::from(2), &p), (p >> 1) + 1);
+
+        let a = hex("0E0A77C19A07DF2F666EA36F7879462C0A78EB28F5C70B3DD35D438DC58F0D9D");
+        assert_eq!(mul_mod(a, inverse(&a, &p), p), U256::one());
+    }
+}
+
+#[test]
+fn zero_maps_to_zero() {
+    assert_eq!(inverse(&U256::zero(), &bn254_p()), U256::zero());
+}
+
+#[test]
+fn field_inverse_rejects_non_canonical_zero() {
+    for zero in [0u64, 7, 14] {
+        assert_eq!(<U256 as Invertible<Multiplicative, Fp_7>>::inverse(&U256::from(zero)), None);
+    }
+    assert_eq!(<U256 as Invertible<Multiplicative, FP_SECP265K1>>::inverse(&secp256k1_p()), None);
+}
+
+#[test]
+fn field_inverse_reduces_non_canonical_input() {
+    // 10 ≡ 3 (mod 7) and 3·5 = 15 ≡ 1
+    assert_eq!(<U256 as Invertible<Multiplicative, Fp_7>>::inverse(&U256::from(10)), Some(U256::from(5)));
+    let p = secp256k1_p();
+    assert_eq!(
+        <U256 as Invertible<Multiplicative, FP_SECP265K1>>::inverse(&(p + 1)),
+        Some(U256::one())
+    );
+}
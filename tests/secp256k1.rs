@@ -0,0 +1,288 @@
+use crypto::algebra::{
+    elliptic_curve::{
+        element::EllipticCurvePoint,
+        impls::{
+            point::{AffinePoint, JacobianPoint},
+            wnaf::wnaf,
+            Secp256k1, N,
+        },
+    },
+    field::fp_impls::fp_secp256k1::P,
+    group::{
+        extension::{Power, ScalarMultiplication},
+        CyclingGroup,
+    },
+    operations::{Additive, BinaryOperation},
+};
+use primitive_types::{U256, U512};
+
+fn hex(s: &str) -> U256 {
+    U256::from_str_radix(s, 16).unwrap()
+}
+
+fn affine(x: &str, y: &str) -> AffinePoint {
+    AffinePoint { x: hex(x), y: hex(y) }
+}
+
+fn g() -> JacobianPoint {
+    Secp256k1.generator()
+}
+
+fn to_affine(p: JacobianPoint) -> Option<AffinePoint> {
+    p.into()
+}
+
+fn two_g() -> AffinePoint {
+    affine(
+        "C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5",
+        "1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A",
+    )
+}
+
+fn three_g() -> AffinePoint {
+    affine(
+        "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+        "388F7B0F632DE8140FE337E62A37F3566500A99934C2231B6CB9FD7584B8E672",
+    )
+}
+
+#[test]
+fn generator_matches_standard_point() {
+    assert_eq!(
+        to_affine(g()),
+        Some(affine(
+            "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+        ))
+    );
+    assert!(g().is_on_curve(&Secp256k1));
+}
+
+#[test]
+fn doubling_generator() {
+    let p = g().double();
+    assert_eq!(to_affine(p), Some(two_g()));
+    assert!(p.is_on_curve(&Secp256k1));
+}
+
+#[test]
+fn addition_matches_doubling_and_vectors() {
+    assert_eq!(to_affine(g().add(&g())), Some(two_g()));
+
+    let p = g().double().add(&g());
+    assert_eq!(to_affine(p), Some(three_g()));
+    assert!(p.is_on_curve(&Secp256k1));
+
+    // Jacobian inputs with Z ≠ 1 on both sides
+    let q = g().double().add(&g().double().double());
+    assert_eq!(to_affine(q), to_affine(p.double()));
+}
+
+#[test]
+fn negation_and_identity() {
+    let p = g().double();
+    assert!(p.add(&p.neg()).is_identity());
+    assert_eq!(to_affine(p.neg()).unwrap().x, two_g().x);
+    assert_eq!(g().add(&JacobianPoint::infinity()), g());
+    assert_eq!(JacobianPoint::infinity().add(&g()), g());
+    assert!(JacobianPoint::infinity().double().is_identity());
+}
+
+#[test]
+fn scalar_multiples_of_generator() {
+    let pow = |k: U256| <Secp256k1 as Power<Additive, Secp256k1>>::pow(&g(), k);
+    assert_eq!(to_affine(pow(U256::from(3))), Some(three_g()));
+    assert!(pow(N).is_identity());
+    assert_eq!(to_affine(pow(N - 1)), to_affine(g().neg()));
+}
+
+#[test]
+fn rejects_point_off_curve() {
+    let mut p = JacobianPoint::from(two_g());
+    p.y = p.y + 1;
+    assert!(!p.is_on_curve(&Secp256k1));
+}
+
+#[test]
+fn reduces_non_canonical_affine_coordinates() {
+    // (1, y) lies on the curve, and x = 1 + p is another representative of 1
+    let y = hex("4218F20AE6C646B363DB68605822FB14264CA8D2587FDD6FBC750D587E76A7EE");
+    let canonical = JacobianPoint::from(AffinePoint { x: U256::one(), y });
+    let lifted = JacobianPoint::from(AffinePoint { x: U256::one() + P, y });
+    assert_eq!(lifted.x, U256::one());
+    assert!(lifted.is_on_curve(&Secp256k1));
+    assert_eq!(lifted, canonical);
+    assert_eq!(to_affine(lifted.double()), to_affine(canonical.double()));
+    assert_eq!(to_affine(lifted.add(&g())), to_affine(canonical.add(&g())));
+}
+
+#[test]
+fn projective_equality() {
+    // 2G computed in Jacobian form has Z ≠ 1 but equals the affine vector
+    let p = g().double();
+    assert_ne!(p.z, U256::one());
+    assert_eq!(p, JacobianPoint::from(two_g()));
+    assert_ne!(p, JacobianPoint::from(three_g()));
+    assert_ne!(p, p.neg());
+    assert_ne!(p, JacobianPoint::infinity());
+}
+
+#[test]
+fn infinity_has_zero_z() {
+    let inf = JacobianPoint::infinity();
+    assert!(inf.z.is_zero());
+    assert_eq!(to_affine(inf), None);
+
+    // Any point with Z = 0 is the point at infinity
+    let scaled = JacobianPoint { x: U256::from(4), y: U256::from(8), z: U256::zero() };
+    assert!(scaled.is_identity());
+    assert_eq!(scaled, inf);
+    assert!(scaled.is_on_curve(&Secp256k1));
+
+    let p = g().double();
+    assert_eq!(p.add(&p.neg()), inf);
+}
+
+#[test]
+fn scalar_multiplication() {
+    assert!(g().mul_scalar(&U256::zero()).is_identity());
+    assert_eq!(g().mul_scalar(&U256::one()), g());
+    assert_eq!(to_affine(g().mul_scalar(&U256::from(2))), Some(two_g()));
+    assert_eq!(to_affine(g().scale(&U256::from(3))), Some(three_g()));
+    assert!(g().mul_scalar(&N).is_identity());
+    assert_eq!(g().mul_scalar(&(N - 1)), g().neg());
+
+    // k·(m·G) = (k·m)·G
+    let k = hex("AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522");
+    let m = U256::from(12345);
+    let p = g().mul_scalar(&m);
+    assert_eq!(p.scale(&k), g().mul_scalar(&k).mul_scalar(&m));
+    assert!(p.scale(&k).is_on_curve(&Secp256k1));
+    assert_eq!(p, <Secp256k1 as Power<Additive, Secp256k1>>::pow(&g(), m));
+}
+
+// Plain left-to-right double-and-add, the reference for the faster multipliers
+fn double_and_add(p: &JacobianPoint, scalar: &U256) -> JacobianPoint {
+    let mut result = JacobianPoint::infinity();
+    for i in (0..scalar.bits()).rev() {
+        result = result.double();
+        if scalar.bit(i) {
+            result = result.add(p);
+        }
+    }
+    result
+}
+
+#[test]
+fn scalar_multiplication_matches_double_and_add() {
+    // Small scalars against repeated addition
+    let mut multiple = JacobianPoint::infinity();
+    for k in 0..40u64 {
+        assert_eq!(g().mul_scalar(&U256::from(k)), multiple);
+        assert_eq!(double_and_add(&g(), &U256::from(k)), multiple);
+        multiple = multiple.add(&g());
+    }
+
+    let p = g().double().add(&g());
+    let k = hex("AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522");
+    for scalar in [k, N - 1, N + 1, U256::MAX, U256::one() << 255, U256::from(0xFFFF_FFFFu64)] {
+        assert_eq!(g().mul_scalar(&scalar), double_and_add(&g(), &scalar));
+        assert_eq!(p.mul_scalar(&scalar), double_and_add(&p, &scalar));
+    }
+}
+
+#[test]
+fn scalar_multiplication_vector() {
+    let k = hex("AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522");
+    assert_eq!(
+        to_affine(g().mul_scalar(&k)),
+        Some(affine(
+            "34F9460F0E4F08393D192B3C5133A6BA099AA0AD9FD54EBCCFACDFA239FF49C6",
+            "0B71EA9BD730FD8923F6D25A7A91E7DD7728A960686CB5A901BB419E0F2CA232",
+        ))
+    );
+}
+
+#[test]
+fn constant_time_addition_edge_cases() {
+    let p = g().double();
+    let inf = JacobianPoint::infinity();
+    assert_eq!(p.add_ct(&g()), g().add(&p));
+    assert_eq!(p.add_ct(&p), p.double());
+    assert_eq!(p.add_ct(&JacobianPoint::from(two_g())), p.double());
+    assert!(p.add_ct(&p.neg()).is_identity());
+    assert_eq!(p.add_ct(&inf), p);
+    assert_eq!(inf.add_ct(&p), p);
+    assert!(inf.add_ct(&inf).is_identity());
+}
+
+#[test]
+fn constant_time_scalar_multiplication() {
+    let k = hex("AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522");
+    for scalar in [U256::zero(), U256::one(), U256::from(2), U256::from(3), k, N - 1, N, U256::MAX] {
+        assert_eq!(g().mul_scalar_ct(&scalar), g().mul_scalar(&scalar));
+    }
+    assert_eq!(to_affine(g().mul_scalar_ct(&U256::from(3))), Some(three_g()));
+    assert_eq!(g().scale(&k), g().mul_scalar(&k));
+}
+
+#[test]
+fn generic_ladder_matches_point_ladder() {
+    // Power::pow_ct runs on op_ct, which is add_ct for points
+    let pow_ct = |p: &JacobianPoint, k: U256| <Secp256k1 as Power<Additive, Secp256k1>>::pow_ct(p, k);
+    let k = hex("AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522");
+    let p = g().mul_scalar(&U256::from(12345));
+    for scalar in [U256::zero(), U256::one(), U256::from(2), U256::from(3), k, N - 1, N, U256::MAX] {
+        assert_eq!(pow_ct(&g(), scalar), g().mul_scalar_ct(&scalar));
+        assert_eq!(pow_ct(&p, scalar), p.mul_scalar_ct(&scalar));
+    }
+    assert!(pow_ct(&JacobianPoint::infinity(), k).is_identity());
+    assert_eq!(to_affine(pow_ct(&g(), U256::from(3))), Some(three_g()));
+
+    // The variable-time op and the branch-free op_ct compute the same sums
+    let op = <JacobianPoint as BinaryOperation<Additive, Secp256k1>>::op;
+    let op_ct = <JacobianPoint as BinaryOperation<Additive, Secp256k1>>::op_ct;
+    for (a, b) in [(g(), p), (p, p), (p, p.neg()), (JacobianPoint::infinity(), p)] {
+        assert_eq!(op(&a, &b), op_ct(&a, &b));
+    }
+}
+
+#[test]
+fn wnaf_recoding() {
+    let k = hex("AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522");
+    for scalar in [U256::zero(), U256::one(), U256::from(7), U256::from(0xFFFF), k, N - 1, U256::MAX] {
+        for width in 2..=8 {
+            let digits = wnaf(&scalar, width);
+            let bound = 1i64 << (width - 1);
+
+            // Σ dᵢ·2ⁱ reconstructs the scalar (with a possible carry past 256 bits)
+            let (mut positive, mut negative) = (U512::zero(), U512::zero());
+            for (i, &d) in digits.iter().enumerate() {
+                let term = U512::from(d.unsigned_abs()) << i;
+                if d > 0 { positive += term } else { negative += term }
+            }
+            assert_eq!(positive - negative, U512::from(scalar));
+
+            for (i, &d) in digits.iter().enumerate() {
+                if d != 0 {
+                    assert!(d % 2 != 0 && (d as i64).abs() < bound);
+                    // Nonzero digits are at least `width` positions apart
+                    assert!(digits[i + 1..].iter().take(width - 1).all(|&e| e == 0));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn fixed_base_generator_multiplication() {
+    let k = hex("AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522");
+    assert!(Secp256k1.mul_generator(&U256::zero()).is_identity());
+    assert_eq!(Secp256k1.mul_generator(&U256::one()), g());
+    assert_eq!(to_affine(Secp256k1.mul_generator(&U256::from(2))), Some(two_g()));
+    assert_eq!(to_affine(Secp256k1.mul_generator(&U256::from(3))), Some(three_g()));
+    assert!(Secp256k1.mul_generator(&N).is_identity());
+    for scalar in [k, N - 1, N + 1, U256::MAX, U256::from(0xF0F0F0F0u64) << 100] {
+        assert_eq!(Secp256k1.mul_generator(&scalar), g().mul_scalar(&scalar));
+    }
+}
@@ -0,0 +1,82 @@
+#![allow(non_camel_case_types)]
+
+use crypto::{
+    algebra::field::{
+        fp_impls::{bn254::FR_BN254, fp7_impl::Fp_7, goldilocks::FP_GOLDILOCKS},
+        sqrt::Sqrt,
+    },
+    helpers::mul_mod,
+};
+use primitive_types::U256;
+
+// Small primes with p ≡ 1 (mod 4), which take the Tonelli–Shanks path:
+// 13 − 1 = 3·2², 17 − 1 = 2⁴.
+crypto::prime_field!(Fp_13, Fp13Modulus, U256([13, 0, 0, 0]));
+crypto::prime_field!(Fp_17, Fp17Modulus, U256([17, 0, 0, 0]));
+
+fn squares_mod(p: u64) -> Vec<u64> {
+    let mut squares: Vec<u64> = (1..p).map(|x| x * x % p).collect();
+    squares.sort();
+    squares.dedup();
+    squares
+}
+
+fn check_small_field<F: Sqrt<U256>>(p: u64) {
+    let squares = squares_mod(p);
+    for a in 1..p {
+        let root = F::sqrt(&U256::from(a));
+        if squares.contains(&a) {
+            let root = root.expect("quadratic residues have a square root").low_u64();
+            assert!(root < p);
+            assert_eq!(root * root % p, a);
+        } else {
+            assert_eq!(root, None, "{a} is not a square modulo {p}");
+        }
+    }
+    assert_eq!(F::sqrt(&U256::zero()), Some(U256::zero()));
+}
+
+#[test]
+fn tonelli_shanks_small_fields() {
+    check_small_field::<Fp_13>(13);
+    check_small_field::<Fp_17>(17);
+}
+
+#[test]
+fn fast_path_small_field() {
+    // 7 ≡ 3 (mod 4)
+    check_small_field::<Fp_7>(7);
+}
+
+#[test]
+fn reduces_non_canonical_input() {
+    // 17 + 2 ≡ 2 = 6² (mod 17), and 13 ≡ 0
+    let root = Fp_17::sqrt(&U256::from(19)).unwrap().low_u64();
+    assert_eq!(root * root % 17, 2);
+    assert_eq!(Fp_13::sqrt(&U256::from(13)), Some(U256::zero()));
+}
+
+#[test]
+fn tonelli_shanks_goldilocks() {
+    // p − 1 = 2³²·(2³² − 1), the worst case for the correction loop
+    let p = U256::from(0xFFFFFFFF00000001u64);
+    for x in [2u64, 3, 12345678901234567, 0xFFFFFFFF00000000] {
+        let square = mul_mod(U256::from(x), U256::from(x), p);
+        let root = FP_GOLDILOCKS::sqrt(&square).unwrap();
+        assert_eq!(mul_mod(root, root, p), square);
+    }
+    // 7 generates the multiplicative group, so it is a non-residue
+    assert_eq!(FP_GOLDILOCKS::sqrt(&U256::from(7)), None);
+    assert_eq!(FP_GOLDILOCKS::sqrt(&U256::zero()), Some(U256::zero()));
+}
+
+#[test]
+fn tonelli_shanks_bn254_scalar_field() {
+    let r = U256::from_str_radix("30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000001", 16).unwrap();
+    let x = U256::from_str_radix("0E0A77C19A07DF2F666EA36F7879462C0A78EB28F5C70B3DD35D438DC58F0D9D", 16).unwrap();
+    let square = mul_mod(x, x, r);
+    let root = FR_BN254::sqrt(&square).unwrap();
+    assert!(root == x || root == r - x);
+    // 5 generates the multiplicative group
+    assert_eq!(FR_BN254::sqrt(&U256::from(5)), None);
+}
@@ -0,0 +1,122 @@
+#![allow(non_camel_case_types)]
+
+use crypto::{
+    algebra::{
+        operations::{Additive, BinaryOperation, Multiplicative},
+        properties::{Finite, Identity, Invertible},
+        ring::zn::Units,
+        AlgebraicStructure,
+    },
+    helpers::{gcd, mul_mod},
+};
+use primitive_types::{U256, U512};
+
+crypto::integer_ring!(Z_15, Z15Modulus, U256([15, 0, 0, 0]));
+// 2²⁵⁶ − 1 = 3·5·17·257·641·65537·…, an odd composite using every bit
+crypto::integer_ring!(Z_MAX, ZMaxModulus, U256::MAX);
+// An even full-width modulus, 2²⁵⁵ + 2¹²⁸ + 6
+crypto::integer_ring!(Z_EVEN, ZEvenModulus, U256([6, 0, 1, 1 << 63]));
+
+fn mul<N>(a: U256, b: U256) -> U256
+where
+    U256: BinaryOperation<Multiplicative, N>,
+    N: AlgebraicStructure<Multiplicative, Element = U256>,
+{
+    <U256 as BinaryOperation<Multiplicative, N>>::op(&a, &b)
+}
+
+#[test]
+fn units_of_z15() {
+    // The units of ℤ/15 are the residues coprime to 3 and 5
+    let units: Vec<u64> = (0..15).filter(|&a| Z_15::is_unit(&U256::from(a))).collect();
+    assert_eq!(units, vec![1, 2, 4, 7, 8, 11, 13, 14]);
+    assert!(Z_15::is_unit(&U256::from(17)));
+    assert!(!Z_15::is_unit(&U256::from(30)));
+
+    for a in 0..15u64 {
+        let a = U256::from(a);
+        match <U256 as Invertible<Multiplicative, Z_15>>::inverse(&a) {
+            Some(inv) => {
+                assert!(Z_15::is_unit(&a));
+                assert_eq!(mul::<Z_15>(a, inv), U256::one());
+            }
+            None => assert!(!Z_15::is_unit(&a)),
+        }
+    }
+    assert_eq!(<U256 as Invertible<Multiplicative, Z_15>>::inverse(&U256::from(2)), Some(U256::from(8)));
+    assert_eq!(<U256 as Invertible<Multiplicative, Z_15>>::inverse(&U256::from(3)), None);
+    assert_eq!(<U256 as Invertible<Multiplicative, Z_15>>::inverse(&U256::zero()), None);
+}
+
+#[test]
+fn ring_operations_of_z15() {
+    let add = |a: u64, b: u64| <U256 as BinaryOperation<Additive, Z_15>>::op(&U256::from(a), &U256::from(b));
+    assert_eq!(add(9, 8), U256::from(2));
+    assert_eq!(mul::<Z_15>(U256::from(9), U256::from(8)), U256::from(12));
+    // Zero divisors: 3·5 = 0
+    assert_eq!(mul::<Z_15>(U256::from(3), U256::from(5)), U256::zero());
+    assert_eq!(<U256 as Invertible<Additive, Z_15>>::inverse(&U256::from(4)), Some(U256::from(11)));
+    assert_eq!(Z_15::default().order(), U256::from(15));
+}
+
+#[test]
+fn unit_group_operation() {
+    type G = Units<Z15Modulus>;
+    let units = [1u64, 2, 4, 7, 8, 11, 13, 14].map(U256::from);
+    for a in units {
+        // Closed under multiplication, with inverses in the group
+        for b in units {
+            assert!(units.contains(&mul::<G>(a, b)));
+        }
+        let inv = <U256 as Invertible<Multiplicative, G>>::inverse(&a).unwrap();
+        assert_eq!(mul::<G>(a, inv), <G as Identity<Multiplicative, G>>::identity());
+    }
+    // (ℤ/15)* ≅ C₂ × C₄, so every unit satisfies a⁴ = 1
+    for a in units {
+        let a2 = mul::<G>(a, a);
+        assert_eq!(mul::<G>(a2, a2), U256::one());
+    }
+    assert_eq!(<U256 as Invertible<Multiplicative, G>>::inverse(&U256::from(6)), None);
+}
+
+#[test]
+fn full_width_composite_moduli() {
+    let a = U256::from_str_radix("F0E1D2C3B4A5968778695A4B3C2D1E0F0123456789ABCDEFFEDCBA9876543210", 16).unwrap();
+    let b = U256::from_str_radix("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364140", 16).unwrap();
+
+    // Products that overflow 256 bits, checked against closed forms:
+    // 2¹²⁸·2¹²⁸ = 2²⁵⁶ ≡ 1 (mod 2²⁵⁶ − 1), 2¹²⁸·2¹²⁷ = 2²⁵⁵ ≡ −(2¹²⁸ + 6) (mod 2²⁵⁵ + 2¹²⁸ + 6)
+    let even = U256([6, 0, 1, 1 << 63]);
+    assert_eq!(mul::<Z_MAX>(U256::one() << 128, U256::one() << 128), U256::one());
+    assert_eq!(mul::<Z_EVEN>(U256::one() << 128, U256::one() << 127), even - (U256::one() << 128) - 6);
+    for n in [U256::MAX, even] {
+        let expected = U256::try_from(a.full_mul(b) % U512::from(n)).unwrap();
+        assert_eq!(mul_mod(a, b, n), expected);
+        assert_eq!(mul_mod(n - 1, n - 1, n), U256::one());
+    }
+    assert_eq!(mul::<Z_MAX>(a, b), mul_mod(a, b, U256::MAX));
+    assert_eq!(mul::<Z_EVEN>(a, b), mul_mod(a, b, even));
+
+    // Inverses exist exactly for the units
+    for (x, n) in [(a, U256::MAX), (b, U256::MAX), (a, even), (U256::from(3), U256::MAX)] {
+        let inv = if n == U256::MAX {
+            <U256 as Invertible<Multiplicative, Z_MAX>>::inverse(&x)
+        } else {
+            <U256 as Invertible<Multiplicative, Z_EVEN>>::inverse(&x)
+        };
+        assert_eq!(inv.is_some(), gcd(x, n) == U256::one());
+        if let Some(inv) = inv {
+            assert_eq!(mul_mod(x, inv, n), U256::one());
+        }
+    }
+    assert!(!Z_MAX::is_unit(&U256::from(3)));
+    assert!(!Z_EVEN::is_unit(&U256::from(2)));
+}
+
+#[test]
+fn plain_u256_multiplication_wraps() {
+    // The bare U256 monoid multiplies in ℤ/2²⁵⁶ instead of panicking on overflow
+    assert_eq!(mul::<U256>(U256::one() << 128, U256::one() << 128), U256::zero());
+    assert_eq!(mul::<U256>(U256::MAX, U256::MAX), U256::one());
+    assert_eq!(mul::<U256>(U256::from(6), U256::from(7)), U256::from(42));
+}